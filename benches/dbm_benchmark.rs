@@ -1,14 +1,81 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
-use dbm_baenk::{DBM, RDBM, RDBM_V1, RDBM_BITVEC, UDBM};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use dbm_baenk::{Bound, Constraint, DbmError, Federation, DBM, RDBM, RDBM_V1, RDBM_BITVEC, UDBM, PARDIBAAL, REFERENCE};
+
+//Every dimension that some benchmark group runs at.
+const DIMENSIONS: [usize; 7] = [20, 50, 75, 100, 300, 600, 1000];
+
+//The reference backend closes with textbook Floyd-Warshall after every operation, so it is only run as a baseline at
+//the dimensions up to this one.
+const BASELINE: &str = "reference";
+const BASELINE_MAX_DIM: usize = 50;
+
+//Every benchmark first builds its DBM through here, so a backend that silently builds a smaller DBM than requested
+//fails loudly instead of reporting numbers for the wrong size. Only dimensions above the documented limit of a
+//backend, or above BASELINE_MAX_DIM for the baseline, are skipped, and those are listed by report_skipped_dimensions
+//before the first group runs. Any other error is a bug in the backend and fails the run.
+fn checked_dbm<T, D: DBM<T>>(name: &str, dbm: Result<D, DbmError>, dim: usize) -> Option<D> {
+    if name == BASELINE && dim > BASELINE_MAX_DIM {
+        return None;
+    }
+    let dbm = match dbm {
+        Ok(dbm) => dbm,
+        Err(DbmError::DimensionTooLarge { .. }) => return None,
+        Err(error) => panic!("{} failed to build a DBM of dimension {}: {}", name, dim, error),
+    };
+    assert_eq!(DBM::dimension(&dbm), dim, "{} built a DBM of the wrong dimension", name);
+    return Some(dbm);
+}
+
+//Ten overlapping bands 2k <= x_clock <= 2k + 3 cut out of `zone`, the workload of the federation benchmarks.
+fn bands<T: Ord + From<i8>, D: DBM<T> + Clone>(zone: &D, clock: usize) -> Federation<T, D> {
+    let mut federation = Federation::new(DBM::dimension(zone));
+    for k in 0..10i8 {
+        let mut band = zone.clone();
+        DBM::restrict(&mut band, 0, clock, Bound::LessThanEqual((-2 * k).into()));
+        DBM::restrict(&mut band, clock, 0, Bound::LessThanEqual((2 * k + 3).into()));
+        federation.add_zone(band);
+    }
+    return federation;
+}
+
+//The zone of the closure benchmarks: every clock equals x_1 and is unbounded, so a bound on x_1 bounds every clock.
+fn tied_clocks<T, D: DBM<T>>(mut zone: D) -> D {
+    DBM::future(&mut zone);
+    return zone;
+}
+
+//A copy of `zone` with x_1 - x_0 tightened, made before every iteration of the closure benchmarks so that Close,
+//CloseIJ and CloseClock all start from the same zone. No backend can tighten a bound without closing, so the native
+//restrict has already propagated it and the benchmarks measure a closure pass that finds nothing left to tighten.
+fn tightened<T: From<i8>, D: DBM<T> + Clone>(zone: &D) -> D {
+    let mut zone = zone.clone();
+    DBM::restrict(&mut zone, 1, 0, Bound::LessThanEqual(10i8.into()));
+    return zone;
+}
 
 macro_rules! generate_benchmarks {
     //Name should be a &str, i.e. a string "like this", type should be the type we're testing.
     ($($name:expr, $type:ty,)*) => {
+        //runs first, so the gaps in the report are explained before the groups print their results
+        pub fn report_skipped_dimensions(_c: &mut Criterion) {
+            for dim in DIMENSIONS.iter() {
+                $(
+                    if $name == BASELINE && *dim > BASELINE_MAX_DIM {
+                        println!("skipping {} at dimension {}: the baseline only runs up to dimension {}", $name, dim, BASELINE_MAX_DIM);
+                    } else if let Err(error @ DbmError::DimensionTooLarge { .. }) = <$type as DBM<_>>::try_init(*dim) {
+                        println!("skipping {} at dimension {}: {}", $name, dim, error);
+                    }
+                )*
+            }
+        }
+
         pub fn zero_benchmark(c: &mut Criterion) {
             let mut group = c.benchmark_group("Zero");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, i| b.iter(|| {let _x:$type = DBM::zero(*i as usize);}));
+                    if checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize).is_some() {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, i| b.iter(|| {let _x:$type = DBM::zero(*i as usize);}));
+                    }
                 )*
             }
         }
@@ -17,7 +84,9 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Init");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, i| b.iter(|| {let _x:$type = DBM::init(*i as usize);}));
+                    if checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize).is_some() {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, i| b.iter(|| {let _x:$type = DBM::init(*i as usize);}));
+                    }
                 )*
             }
         }
@@ -26,11 +95,40 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Inclusion");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    let x:$type = DBM::zero(*i as usize);
-                    let y:$type = DBM::zero(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), &(x, y), |b, (x, y)| b.iter(|| {
-                        DBM::is_included_in(x, y)
-                    }));
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        let y = x.clone();
+                        group.bench_with_input(BenchmarkId::new($name, i), &(x, y), |b, (x, y)| b.iter(|| {
+                            DBM::is_included_in(x, y)
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn relation_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("Relation");
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        let y = x.clone();
+                        group.bench_with_input(BenchmarkId::new($name, i), &(x, y), |b, (x, y)| b.iter(|| {
+                            DBM::relation(x, y)
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn relation_inclusion_pair_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("RelationInclusionPair"); //the two-call pattern that relation replaces
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        let y = x.clone();
+                        group.bench_with_input(BenchmarkId::new($name, i), &(x, y), |b, (x, y)| b.iter(|| {
+                            (DBM::is_included_in(x, y), DBM::is_included_in(y, x))
+                        }));
+                    }
                 )*
             }
         }
@@ -39,10 +137,11 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Satisfied");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    let x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), &x, |b, x| b.iter(|| {
-                        DBM::is_satisfied(x, 1, 0, false, 10);
-                    }));
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), &x, |b, x| b.iter(|| {
+                            DBM::is_satisfied(x, 1, 0, Bound::LessThanEqual(10));
+                        }));
+                    }
                 )*
             }
         }
@@ -51,10 +150,43 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Close");
             for i in [20u64, 50u64, 75u64, 100u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::close(&mut x);
-                    }));
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        let x = tied_clocks(x);
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter_batched(|| tightened(&x), |mut x| {
+                            DBM::close(&mut x);
+                            return x;
+                        }, BatchSize::LargeInput));
+                    }
+                )*
+            }
+        }
+
+        pub fn close_ij_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("CloseIJ"); //the incremental counterparts of Close, at the same dimensions and on the same zones
+            for i in [20u64, 50u64, 75u64, 100u64].iter() {
+                $(
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        let x = tied_clocks(x);
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter_batched(|| tightened(&x), |mut x| {
+                            DBM::close_ij(&mut x, 1, 0);
+                            return x;
+                        }, BatchSize::LargeInput));
+                    }
+                )*
+            }
+        }
+
+        pub fn close_clock_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("CloseClock");
+            for i in [20u64, 50u64, 75u64, 100u64].iter() {
+                $(
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        let x = tied_clocks(x);
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter_batched(|| tightened(&x), |mut x| {
+                            DBM::close_clock(&mut x, 1);
+                            return x;
+                        }, BatchSize::LargeInput));
+                    }
                 )*
             }
         }
@@ -63,10 +195,11 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Future");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::future(&mut x);
-                    }));
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::future(&mut x);
+                        }));
+                    }
                 )*
             }
         }
@@ -75,10 +208,71 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Past");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::past(&mut x);
-                    }));
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::past(&mut x);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn future_within_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("FutureWithin");
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        let invariant = [Constraint::new(1, 0, Bound::LessThanEqual(10)), Constraint::new(2, 0, Bound::LessThan(20)), Constraint::new(2, 1, Bound::LessThanEqual(5))];
+                        group.bench_with_input(BenchmarkId::new($name, i), &invariant, |b, invariant| b.iter(|| {
+                            DBM::future_within(&mut x, invariant);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn future_then_restrict_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("FutureThenRestrict"); //the unfused sequence that future_within replaces
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_zero(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::future(&mut x);
+                            DBM::restrict(&mut x, 1, 0, Bound::LessThanEqual(10));
+                            DBM::restrict(&mut x, 2, 0, Bound::LessThan(20));
+                            DBM::restrict(&mut x, 2, 1, Bound::LessThanEqual(5));
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn past_within_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("PastWithin");
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        let guard = [Constraint::new(0, 1, Bound::LessThanEqual(-10)), Constraint::new(2, 0, Bound::LessThan(20)), Constraint::new(1, 2, Bound::LessThanEqual(5))];
+                        group.bench_with_input(BenchmarkId::new($name, i), &guard, |b, guard| b.iter(|| {
+                            DBM::past_within(&mut x, guard);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn past_then_restrict_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("PastThenRestrict"); //the unfused sequence that past_within replaces
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::past(&mut x);
+                            DBM::restrict(&mut x, 0, 1, Bound::LessThanEqual(-10));
+                            DBM::restrict(&mut x, 2, 0, Bound::LessThan(20));
+                            DBM::restrict(&mut x, 1, 2, Bound::LessThanEqual(5));
+                        }));
+                    }
                 )*
             }
         }
@@ -87,10 +281,11 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Restrict");
             for i in [20u64, 100u64, 300u64, 600u64, 1000u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::restrict(&mut x, 1, 0, false, 10);
-                    }));
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::restrict(&mut x, 1, 0, Bound::LessThanEqual(10));
+                        }));
+                    }
                 )*
             }
         }
@@ -99,10 +294,11 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Free");
             for i in [20u64, 100u64, 300u64, 600u64, 1000u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::free(&mut x, 1);
-                    }));
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::free(&mut x, 1);
+                        }));
+                    }
                 )*
             }
         }
@@ -111,10 +307,11 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Assign");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::assign(&mut x, 1, 10);
-                    }));
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::assign(&mut x, 1, 10);
+                        }));
+                    }
                 )*
             }
         }
@@ -123,10 +320,11 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Copy");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::copy(&mut x, 1, 2); //nb: Don't run this on DBMs with dim < 3
-                    }));
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::copy(&mut x, 1, 2); //nb: Don't run this on DBMs with dim < 3
+                        }));
+                    }
                 )*
             }
         }
@@ -135,27 +333,167 @@ macro_rules! generate_benchmarks {
             let mut group = c.benchmark_group("Shift");
             for i in [20u64, 100u64, 300u64, 600u64].iter() {
                 $(
-                    let mut x:$type = DBM::init(*i as usize);
-                    group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
-                        DBM::shift(&mut x, 1, 10);
-                    }));
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        //every iteration shifts a fresh copy, so that the bounds never grow past the constant limit of the backend
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter_batched(|| x.clone(), |mut x| {
+                            DBM::shift(&mut x, 1, 10);
+                            return x;
+                        }, BatchSize::LargeInput));
+                    }
+                )*
+            }
+        }
+
+        pub fn update_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("Update");
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::update(&mut x, 1, 2, 10);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn update_copy_shift_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("UpdateCopyShift"); //the unfused sequence that update replaces
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::copy(&mut x, 1, 2);
+                            DBM::shift(&mut x, 1, 10);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn reset_many_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("ResetMany");
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::reset_many(&mut x, &[(1, 0), (2, 10), (3, 0), (4, 10)]);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn reset_chained_assign_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("ResetChainedAssign");
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        group.bench_with_input(BenchmarkId::new($name, i), i, |b, _i| b.iter(|| {
+                            DBM::assign(&mut x, 1, 0);
+                            DBM::assign(&mut x, 2, 10);
+                            DBM::assign(&mut x, 3, 0);
+                            DBM::assign(&mut x, 4, 10);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn intersection_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("Intersection");
+            for i in [20u64, 100u64, 300u64, 600u64].iter() {
+                $(
+                    if let Some(mut x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        let mut y = x.clone();
+                        DBM::restrict(&mut y, 1, 0, Bound::LessThanEqual(10));
+                        DBM::restrict(&mut y, 0, 2, Bound::LessThanEqual(-5));
+                        group.bench_with_input(BenchmarkId::new($name, i), &y, |b, y| b.iter(|| {
+                            DBM::intersect(&mut x, y);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn federation_union_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("FederationUnionReduce");
+            for i in [20u64, 50u64, 100u64].iter() {
+                $(
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        let federations = (bands(&x, 1), bands(&x, 2));
+                        group.bench_with_input(BenchmarkId::new($name, i), &federations, |b, (x, y)| b.iter(|| {
+                            let mut union = x.clone();
+                            union.union(y);
+                            union.union(x);
+                            union.reduce();
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn federation_intersection_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("FederationIntersection");
+            for i in [20u64, 50u64, 100u64].iter() {
+                $(
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        let federations = (bands(&x, 1), bands(&x, 2));
+                        group.bench_with_input(BenchmarkId::new($name, i), &federations, |b, (x, y)| b.iter(|| {
+                            let mut intersection = x.clone();
+                            intersection.intersect(y);
+                        }));
+                    }
+                )*
+            }
+        }
+
+        pub fn federation_inclusion_benchmark(c: &mut Criterion) {
+            let mut group = c.benchmark_group("FederationInclusion");
+            for i in [20u64, 50u64, 100u64].iter() {
+                $(
+                    if let Some(x) = checked_dbm::<_, $type>($name, DBM::try_init(*i as usize), *i as usize) {
+                        let federation = bands(&x, 1);
+                        let mut union = federation.clone();
+                        union.union(&bands(&x, 2));
+                        group.bench_with_input(BenchmarkId::new($name, i), &(federation, union), |b, (x, y)| b.iter(|| {
+                            x.is_included_in(y)
+                        }));
+                    }
                 )*
             }
         }
 
         criterion_group!(benches,
+                         report_skipped_dimensions,
                          zero_benchmark,
                          init_benchmark,
                          inclusion_benchmark,
+                         relation_benchmark,
+                         relation_inclusion_pair_benchmark,
                          satisfied_benchmark,
                          close_benchmark,
+                         close_ij_benchmark,
+                         close_clock_benchmark,
                          future_benchmark,
                          past_benchmark,
+                         future_within_benchmark,
+                         future_then_restrict_benchmark,
+                         past_within_benchmark,
+                         past_then_restrict_benchmark,
                          restrict_benchmark,
                          free_benchmark,
                          assign_benchmark,
                          copy_benchmark,
                          shift_benchmark,
+                         update_benchmark,
+                         update_copy_shift_benchmark,
+                         reset_many_benchmark,
+                         reset_chained_assign_benchmark,
+                         intersection_benchmark,
+                         federation_union_benchmark,
+                         federation_intersection_benchmark,
+                         federation_inclusion_benchmark,
 
         );
         criterion_main!(benches);
@@ -166,8 +504,11 @@ generate_benchmarks! {
     "udbm", UDBM,
     "rdbm_8bit", RDBM<i8>,
     "rdbm_32bit", RDBM<i32>,
+    "rdbm_64bit", RDBM<i64>,
     "rdbm_v1_8bit", RDBM_V1<i8>,
     "rdbm_v1_32bit", RDBM_V1<i32>,
     "rdbm_bitvec_8bit", RDBM_BITVEC<i8>,
     "rdbm_bitvec_32bit", RDBM_BITVEC<i32>,
+    "pardibaal", PARDIBAAL,
+    "reference", REFERENCE,
 }
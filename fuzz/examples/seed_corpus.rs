@@ -0,0 +1,21 @@
+//Writes the seed sequences into fuzz/corpus, one file per sequence and target.
+
+use dbm_baenk_fuzz::{encode, encode_pair, pair_seeds, seeds};
+use std::fs;
+use std::path::Path;
+
+fn write(target: &str, name: &str, data: &[u8]) -> std::io::Result<()> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus").join(target);
+    fs::create_dir_all(&directory)?;
+    return fs::write(directory.join(name), data);
+}
+
+fn main() -> std::io::Result<()> {
+    for (name, case) in seeds() {
+        write("operations", name, &encode(&case))?;
+    }
+    for (name, first, second) in pair_seeds() {
+        write("pairs", name, &encode_pair(&first, &second))?;
+    }
+    return Ok(());
+}
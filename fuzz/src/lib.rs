@@ -0,0 +1,482 @@
+//! Decoding of fuzzer input into DBM operation sequences, and the checks run by the fuzz targets.
+//!
+//! The first byte of an input picks the dimension, up to the 256 clocks of `RDBM_V1`, and the
+//! second whether the zone starts from `zero` or `init`. Every following operation is an opcode
+//! byte followed by its arguments, and input that ends in the middle of an operation ends the
+//! sequence. Clock indices are reduced modulo one more than the dimension, and clocks that cannot
+//! be the reference clock are one more than a number reduced modulo the dimension, so both reach
+//! one past the last clock and out-of-range clocks are exercised too. Every backend must reject
+//! them with the same error. Arguments take one byte each, except indices at dimension 256, which
+//! take two as one byte cannot reach index 256. Constants take the full range of `i8`.
+//!
+//! The backends are run at 32 and 64 bits, where these constants never overflow, and must agree
+//! with the reference backend exactly. The rdbm variants are also run at `i8`, where constants
+//! beyond 42 overflow; they must agree with the reference backend up to their first overflow.
+//!
+//! Run a target with `cargo fuzz run operations` from the repository root. The seed corpus in
+//! `fuzz/corpus` is written by `cargo run --example seed_corpus` from the sequences in `seeds`,
+//! and `cargo test` in this directory replays it without libFuzzer.
+
+mod seeds;
+
+pub use seeds::{pair_seeds, seeds};
+
+use dbm_baenk::{Bound, Constraint, DbmError, Relation, DBM, PARDIBAAL, RDBM, RDBM_BITVEC, RDBM_V1, REFERENCE, UDBM};
+use std::convert::TryFrom;
+use std::ops::Add;
+
+//the largest dimension of RDBM_V1
+const MAX_DIM: usize = 256;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operation {
+    Future,
+    Past,
+    Close,
+    Restrict(Constraint<i8>),
+    Free(usize),
+    Assign(usize, i8),
+    Copy(usize, usize),
+    Shift(usize, i8),
+    Update(usize, usize, i8),
+    CloseIJ(usize, usize),
+    CloseClock(usize),
+    FutureWithin(Vec<Constraint<i8>>),
+    PastWithin(Vec<Constraint<i8>>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Case {
+    pub dim: usize,
+    pub zero: bool,
+    pub operations: Vec<Operation>,
+}
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    dim: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn byte(&mut self) -> Option<u8> {
+        let (&byte, rest) = self.data.split_first()?;
+        self.data = rest;
+        return Some(byte);
+    }
+
+    //a number below `modulus`, from two bytes when one byte cannot reach every such number
+    fn number(&mut self, modulus: usize) -> Option<usize> {
+        let number = match modulus > 256 {
+            true => u16::from_le_bytes([self.byte()?, self.byte()?]) as usize,
+            false => self.byte()? as usize,
+        };
+        return Some(number % modulus);
+    }
+
+    //any clock, the reference clock included, or one past the last
+    fn index(&mut self) -> Option<usize> {
+        return self.number(self.dim + 1);
+    }
+
+    //a clock other than the reference clock, or one past the last
+    fn clock(&mut self) -> Option<usize> {
+        return Some(1 + self.number(self.dim)?);
+    }
+
+    fn value(&mut self) -> Option<i8> {
+        return Some(self.byte()? as i8);
+    }
+
+    //j is decoded as an offset from i, so that the two always differ
+    fn constraint(&mut self) -> Option<Constraint<i8>> {
+        let i = self.index()?;
+        let j = (i + 1 + self.number(self.dim)?) % (self.dim + 1);
+        let bound = match self.byte()? % 3 {
+            0 => Bound::Infinity,
+            1 => Bound::LessThan(self.byte()? as i8),
+            _ => Bound::LessThanEqual(self.byte()? as i8),
+        };
+        return Some(Constraint::new(i, j, bound));
+    }
+
+    fn constraints(&mut self) -> Option<Vec<Constraint<i8>>> {
+        let count = self.byte()? % 4;
+        return (0..count).map(|_| self.constraint()).collect();
+    }
+
+    fn operation(&mut self) -> Option<Operation> {
+        return Some(match self.byte()? % 13 {
+            0 => Operation::Future,
+            1 => Operation::Past,
+            2 => Operation::Close,
+            3 => Operation::Restrict(self.constraint()?),
+            4 => Operation::Free(self.clock()?),
+            5 => Operation::Assign(self.clock()?, self.value()?),
+            6 => Operation::Copy(self.clock()?, self.clock()?),
+            7 => Operation::Shift(self.clock()?, self.value()?),
+            8 => Operation::Update(self.clock()?, self.clock()?, self.value()?),
+            9 => Operation::CloseIJ(self.index()?, self.index()?),
+            10 => Operation::CloseClock(self.index()?),
+            11 => Operation::FutureWithin(self.constraints()?),
+            _ => Operation::PastWithin(self.constraints()?),
+        });
+    }
+
+    fn operations(&mut self, count: usize) -> Vec<Operation> {
+        return std::iter::from_fn(|| self.operation()).take(count).collect();
+    }
+}
+
+fn start(data: &[u8]) -> Option<(Decoder<'_>, bool)> {
+    let (&first, data) = data.split_first()?;
+    let (&second, data) = data.split_first()?;
+    let dim = 2 + first as usize % (MAX_DIM - 1);
+    return Some((Decoder { data, dim }, second & 1 == 1));
+}
+
+/// The operation sequence encoded by `data`, or `None` if it is too short to hold a dimension
+/// and a starting zone.
+pub fn decode(data: &[u8]) -> Option<Case> {
+    let (mut decoder, zero) = start(data)?;
+    let operations = decoder.operations(usize::MAX);
+    return Some(Case { dim: decoder.dim, zero, operations });
+}
+
+/// Two zones of one dimension, each with its own operation sequence. The byte after the first
+/// two holds the length of the first sequence.
+pub fn decode_pair(data: &[u8]) -> Option<(Case, Case)> {
+    let (mut decoder, zero) = start(data)?;
+    let length = decoder.byte()? as usize;
+    let first = decoder.operations(length);
+    let second = decoder.operations(usize::MAX);
+    return Some((Case { dim: decoder.dim, zero, operations: first }, Case { dim: decoder.dim, zero: !zero, operations: second }));
+}
+
+struct Encoder {
+    data: Vec<u8>,
+    dim: usize,
+}
+
+impl Encoder {
+    fn number(&mut self, number: usize, modulus: usize) {
+        return match modulus > 256 {
+            true => self.data.extend_from_slice(&(number as u16).to_le_bytes()),
+            false => self.data.push(number as u8),
+        };
+    }
+
+    fn index(&mut self, index: usize) {
+        self.number(index, self.dim + 1);
+    }
+
+    fn clock(&mut self, clock: usize) {
+        self.number(clock - 1, self.dim);
+    }
+
+    fn constraint(&mut self, constraint: &Constraint<i8>) {
+        let offset = (constraint.j + self.dim - constraint.i) % (self.dim + 1);
+        self.index(constraint.i);
+        self.number(offset, self.dim);
+        return match constraint.bound {
+            Bound::Infinity => self.data.push(0),
+            Bound::LessThan(constant) => self.data.extend_from_slice(&[1, constant as u8]),
+            Bound::LessThanEqual(constant) => self.data.extend_from_slice(&[2, constant as u8]),
+        };
+    }
+
+    fn constraints(&mut self, constraints: &[Constraint<i8>]) {
+        self.data.push(constraints.len() as u8);
+        constraints.iter().for_each(|constraint| self.constraint(constraint));
+    }
+
+    fn operation(&mut self, operation: &Operation) {
+        return match operation {
+            Operation::Future => self.data.push(0),
+            Operation::Past => self.data.push(1),
+            Operation::Close => self.data.push(2),
+            Operation::Restrict(constraint) => {
+                self.data.push(3);
+                self.constraint(constraint);
+            }
+            Operation::Free(clock) => {
+                self.data.push(4);
+                self.clock(*clock);
+            }
+            Operation::Assign(clock, value) => {
+                self.data.push(5);
+                self.clock(*clock);
+                self.data.push(*value as u8);
+            }
+            Operation::Copy(clock_to, clock_from) => {
+                self.data.push(6);
+                self.clock(*clock_to);
+                self.clock(*clock_from);
+            }
+            Operation::Shift(clock, value) => {
+                self.data.push(7);
+                self.clock(*clock);
+                self.data.push(*value as u8);
+            }
+            Operation::Update(clock_to, clock_from, value) => {
+                self.data.push(8);
+                self.clock(*clock_to);
+                self.clock(*clock_from);
+                self.data.push(*value as u8);
+            }
+            Operation::CloseIJ(i, j) => {
+                self.data.push(9);
+                self.index(*i);
+                self.index(*j);
+            }
+            Operation::CloseClock(clock) => {
+                self.data.push(10);
+                self.index(*clock);
+            }
+            Operation::FutureWithin(invariant) => {
+                self.data.push(11);
+                self.constraints(invariant);
+            }
+            Operation::PastWithin(guard) => {
+                self.data.push(12);
+                self.constraints(guard);
+            }
+        };
+    }
+}
+
+fn encoder(case: &Case) -> Encoder {
+    return Encoder { data: vec![(case.dim - 2) as u8, case.zero as u8], dim: case.dim };
+}
+
+/// The input that `decode` turns back into `case`.
+pub fn encode(case: &Case) -> Vec<u8> {
+    let mut encoder = encoder(case);
+    case.operations.iter().for_each(|operation| encoder.operation(operation));
+    return encoder.data;
+}
+
+/// The input that `decode_pair` turns back into the two cases, which must share a dimension and
+/// start from opposite zones.
+pub fn encode_pair(first: &Case, second: &Case) -> Vec<u8> {
+    let mut encoder = encoder(first);
+    encoder.data.push(first.operations.len() as u8);
+    first.operations.iter().chain(second.operations.iter()).for_each(|operation| encoder.operation(operation));
+    return encoder.data;
+}
+
+fn convert<T: From<i8>>(bound: Bound<i8>) -> Bound<T> {
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(constant.into()),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(constant.into()),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+fn widen<T: Into<i64>>(bound: Bound<T>) -> Bound<i64> {
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(constant.into()),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(constant.into()),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+fn constraints<T: From<i8>>(constraints: &[Constraint<i8>]) -> Vec<Constraint<T>> {
+    return constraints
+        .iter()
+        .map(|constraint| Constraint::new(constraint.i, constraint.j, convert(constraint.bound)))
+        .collect();
+}
+
+fn apply<T: From<i8> + Clone + Ord + Add<Output = T>, D: DBM<T>>(dbm: &mut D, operation: &Operation) -> Result<(), DbmError> {
+    return match operation {
+        Operation::Future => D::try_future(dbm),
+        Operation::Past => D::try_past(dbm),
+        Operation::Close => D::try_close(dbm).map(|_| ()),
+        Operation::Restrict(constraint) => D::try_restrict(dbm, constraint.i, constraint.j, convert(constraint.bound)),
+        Operation::Free(clock) => D::try_free(dbm, *clock),
+        Operation::Assign(clock, value) => D::try_assign(dbm, *clock, T::from(*value)),
+        Operation::Copy(clock_to, clock_from) => D::try_copy(dbm, *clock_to, *clock_from),
+        Operation::Shift(clock, value) => D::try_shift(dbm, *clock, T::from(*value)),
+        Operation::Update(clock_to, clock_from, value) => D::try_update(dbm, *clock_to, *clock_from, T::from(*value)),
+        Operation::CloseIJ(i, j) => D::try_close_ij(dbm, *i, *j).map(|_| ()),
+        Operation::CloseClock(clock) => D::try_close_clock(dbm, *clock).map(|_| ()),
+        Operation::FutureWithin(invariant) => D::try_future_within(dbm, &constraints(invariant)),
+        Operation::PastWithin(guard) => D::try_past_within(dbm, &constraints(guard)),
+    };
+}
+
+/// The bounds of a zone, or `None` if it is empty, since empty zones have no canonical bounds.
+pub type Bounds = Option<Vec<Vec<Bound<i64>>>>;
+
+/// The result of every operation in a sequence, and the zone after it.
+pub type Trace = Vec<(Result<(), DbmError>, Bounds)>;
+
+fn bounds<T: Into<i64>, D: DBM<T>>(dbm: &D) -> Bounds {
+    return match D::is_empty(dbm) {
+        true => None,
+        false => Some(D::to_matrix(dbm).into_iter().map(|row| row.into_iter().map(widen).collect()).collect()),
+    };
+}
+
+fn initial<T, D: DBM<T>>(case: &Case) -> D {
+    return match case.zero {
+        true => D::zero(case.dim),
+        false => D::init(case.dim),
+    };
+}
+
+fn build<T, D>(case: &Case, trace: &mut Trace) -> D
+where
+    T: From<i8> + Into<i64> + Clone + Ord + Add<Output = T>,
+    D: DBM<T>,
+{
+    let mut dbm = initial(case);
+    for operation in case.operations.iter() {
+        //operations on empty zones are undefined in udbm, so the sequence ends with the zone
+        if D::is_empty(&dbm) {
+            break;
+        }
+        let result = apply(&mut dbm, operation);
+        trace.push((result, bounds(&dbm)));
+    }
+    return dbm;
+}
+
+/// Runs the sequence on one backend, up to the operation that empties the zone.
+pub fn trace<T, D>(case: &Case) -> Trace
+where
+    T: From<i8> + Into<i64> + Clone + Ord + Add<Output = T>,
+    D: DBM<T>,
+{
+    let mut trace = Vec::new();
+    build::<T, D>(case, &mut trace);
+    return trace;
+}
+
+/// What the binary operations make of two zones.
+#[derive(Debug, PartialEq)]
+pub struct Combination {
+    pub relation: Result<Relation, DbmError>,
+    pub inclusions: (bool, bool),
+    pub intersection: Result<Bounds, DbmError>,
+    pub convex_hull: Bounds,
+}
+
+/// The traces of both zones, and their combination if neither is empty.
+pub fn combine<T, D>(first: &Case, second: &Case) -> (Trace, Option<Combination>)
+where
+    T: From<i8> + Into<i64> + TryFrom<i64> + Clone + Ord + Add<Output = T>,
+    D: DBM<T> + Clone,
+{
+    let mut trace = Vec::new();
+    let lhs: D = build(first, &mut trace);
+    let rhs: D = build(second, &mut trace);
+    if D::is_empty(&lhs) || D::is_empty(&rhs) {
+        return (trace, None);
+    }
+    let mut intersection = lhs.clone();
+    let intersection = D::try_intersect(&mut intersection, &rhs).map(|_| bounds(&intersection));
+    let mut convex_hull = lhs.clone();
+    D::convex_hull(&mut convex_hull, &rhs);
+    let combination = Combination {
+        relation: D::try_relation(&lhs, &rhs),
+        inclusions: (D::is_included_in(&lhs, &rhs), D::is_included_in(&rhs, &lhs)),
+        intersection,
+        convex_hull: bounds(&convex_hull),
+    };
+    return (trace, Some(combination));
+}
+
+//every backend with the constant type it is fuzzed with, the reference backend first
+macro_rules! for_each_backend {
+    ($function:ident, $($argument:expr),*) => {
+        vec![
+            ("reference", $function::<i64, REFERENCE>($($argument),*)),
+            ("udbm", $function::<i32, UDBM>($($argument),*)),
+            ("rdbm", $function::<i32, RDBM<i32>>($($argument),*)),
+            ("rdbm_v1", $function::<i32, RDBM_V1<i32>>($($argument),*)),
+            ("rdbm_bitvec", $function::<i32, RDBM_BITVEC<i32>>($($argument),*)),
+            ("pardibaal", $function::<i32, PARDIBAAL>($($argument),*)),
+        ]
+    };
+}
+
+//the rdbm variants at i8, whose limit of 42 the decoded constants cross
+macro_rules! for_each_narrow_backend {
+    ($function:ident, $($argument:expr),*) => {
+        vec![
+            ("rdbm_i8", $function::<i8, RDBM<i8>>($($argument),*)),
+            ("rdbm_v1_i8", $function::<i8, RDBM_V1<i8>>($($argument),*)),
+            ("rdbm_bitvec_i8", $function::<i8, RDBM_BITVEC<i8>>($($argument),*)),
+        ]
+    };
+}
+
+fn assert_agree<R: PartialEq + std::fmt::Debug>(results: Vec<(&str, R)>, input: &dyn std::fmt::Debug) {
+    let (reference, expected) = &results[0];
+    for (name, result) in results.iter().skip(1) {
+        assert!(result == expected, "{} diverges from {} on {:#?}\n{}: {:?}\n{}: {:?}", name, reference, input, reference, expected, name, result);
+    }
+}
+
+//the number of steps before the first one that overflows
+fn overflow_free(trace: &Trace) -> usize {
+    return trace.iter().position(|(result, _)| *result == Err(DbmError::Overflow)).unwrap_or(trace.len());
+}
+
+//asserts that a backend with a smaller limit agrees with the reference backend up to the step where it overflows, and
+//returns whether it overflowed
+fn assert_agree_until_overflow(reference: &(&str, Trace), narrow: &(&str, Trace), input: &dyn std::fmt::Debug) -> bool {
+    let ((reference_name, expected), (name, trace)) = (reference, narrow);
+    let steps = overflow_free(trace);
+    assert_agree(vec![(*reference_name, &expected[..steps.min(expected.len())]), (*name, &trace[..steps])], input);
+    return steps < trace.len();
+}
+
+/// Runs the sequence encoded by `data` on every backend and panics if any backend diverges from
+/// the reference backend. The backends at `i8` only need to agree up to their first overflow,
+/// and a single-step operation that overflows must leave their zone unchanged.
+pub fn check_operations(data: &[u8]) {
+    if let Some(case) = decode(data) {
+        let results = for_each_backend!(trace, &case);
+        for (name, trace) in for_each_narrow_backend!(trace, &case) {
+            if !assert_agree_until_overflow(&results[0], &(name, trace.clone()), &case) {
+                continue;
+            }
+            let step = overflow_free(&trace);
+            if let Operation::Restrict(_) | Operation::Assign(..) | Operation::Shift(..) = case.operations[step] {
+                let before = match step {
+                    0 => bounds(&initial::<i64, REFERENCE>(&case)),
+                    _ => trace[step - 1].1.clone(),
+                };
+                assert!(trace[step].1 == before, "{} changed the zone when step {} overflowed in {:#?}", name, step, case);
+            }
+        }
+        assert_agree(results, &case);
+    }
+}
+
+/// Like `check_operations` for two zones, also comparing what the binary operations make of them.
+/// If neither zone of a backend at `i8` overflows, only their intersection may.
+pub fn check_pair(data: &[u8]) {
+    if let Some((first, second)) = decode_pair(data) {
+        let input = (&first, &second);
+        let results = for_each_backend!(combine, &first, &second);
+        let (reference, (reference_trace, expected)) = &results[0];
+        for (name, (trace, combination)) in for_each_narrow_backend!(combine, &first, &second) {
+            if assert_agree_until_overflow(&(reference, reference_trace.clone()), &(name, trace), &input) {
+                continue;
+            }
+            let other_results = |combination: &Option<Combination>| {
+                combination.as_ref().map(|c| (c.relation.clone(), c.inclusions, c.convex_hull.clone()))
+            };
+            assert_agree(vec![(*reference, other_results(expected)), (name, other_results(&combination))], &input);
+            if let Some(Combination { intersection: Err(DbmError::Overflow), .. }) = combination {
+                continue;
+            }
+            let intersection = |combination: &Option<Combination>| combination.as_ref().map(|c| c.intersection.clone());
+            assert_agree(vec![(*reference, intersection(expected)), (name, intersection(&combination))], &input);
+        }
+        assert_agree(results, &input);
+    }
+}
@@ -0,0 +1,99 @@
+//The operation sequences of the unit tests in src/lib.rs, as a starting point for the fuzzer, with sequences that
+//overflow the backends at i8 and sequences at the largest dimension.
+
+use crate::{Case, Operation};
+use dbm_baenk::{Bound, Constraint};
+
+fn case(dim: usize, zero: bool, operations: Vec<Operation>) -> Case {
+    return Case { dim, zero, operations };
+}
+
+fn restrict(i: usize, j: usize, bound: Bound<i8>) -> Operation {
+    return Operation::Restrict(Constraint::new(i, j, bound));
+}
+
+/// Named sequences for the `operations` target.
+pub fn seeds() -> Vec<(&'static str, Case)> {
+    use Bound::{Infinity, LessThan, LessThanEqual};
+    use Operation::*;
+    return vec![
+        ("future_included_in", case(10, true, vec![Future])),
+        ("restrict", case(10, false, vec![restrict(1, 0, LessThanEqual(10)), restrict(0, 1, LessThan(-5))])),
+        ("restrict_lower_closed", case(10, false, vec![restrict(0, 1, LessThanEqual(-10)), Close])),
+        ("empty_negative_cycle", case(10, false, vec![restrict(1, 2, LessThanEqual(-1)), restrict(2, 3, LessThanEqual(-1)), restrict(3, 1, LessThanEqual(1))])),
+        ("empty_after_assign", case(10, false, vec![Assign(1, 10), restrict(1, 0, LessThan(10))])),
+        ("close_ij_after_restrict", case(5, true, vec![Future, restrict(1, 0, LessThanEqual(6)), CloseIJ(1, 0), restrict(0, 2, LessThan(-6)), CloseIJ(0, 2)])),
+        ("close_clock_after_assign", case(4, true, vec![Future, Assign(2, 3), CloseClock(2)])),
+        (
+            "future_within",
+            case(4, true, vec![Assign(2, 3), FutureWithin(vec![Constraint::new(1, 0, LessThanEqual(5)), Constraint::new(2, 1, LessThan(4)), Constraint::new(3, 0, Infinity)])]),
+        ),
+        (
+            "past_within",
+            case(
+                4,
+                false,
+                vec![
+                    restrict(0, 1, LessThanEqual(-4)),
+                    restrict(1, 0, LessThanEqual(6)),
+                    restrict(2, 1, LessThanEqual(1)),
+                    PastWithin(vec![Constraint::new(0, 1, LessThan(-1)), Constraint::new(3, 0, LessThanEqual(2))]),
+                ],
+            ),
+        ),
+        ("restrict_infinity", case(3, false, vec![restrict(1, 0, Infinity), restrict(2, 1, LessThanEqual(3))])),
+        ("free_and_copy", case(10, true, vec![Free(1), Copy(1, 2)])),
+        ("multiple_shift", case(10, false, vec![Assign(1, 10), Shift(1, 10), Shift(1, 10), Shift(1, 10)])),
+        ("update", case(10, true, vec![Assign(2, 10), Update(1, 2, 5)])),
+        ("update_same_clock", case(10, false, vec![Assign(1, 10), Update(1, 1, 10)])),
+        ("out_of_range", case(3, false, vec![restrict(3, 1, LessThanEqual(2)), Free(3), CloseClock(3), Future])),
+        ("overflow_constant", case(3, false, vec![restrict(1, 0, LessThanEqual(100)), Assign(2, 127), Shift(1, -128), restrict(0, 2, LessThan(-128))])),
+        ("overflow_closure_sum", case(3, false, vec![restrict(1, 0, LessThanEqual(40)), restrict(2, 1, LessThanEqual(40))])),
+        ("overflow_shift_chain", case(3, false, vec![Assign(1, 40), Shift(1, 1), Shift(1, 1), Shift(1, 1)])),
+        ("overflow_update", case(3, true, vec![Assign(2, 42), Update(1, 2, 1)])),
+        ("overflow_future_within", case(3, false, vec![restrict(0, 1, LessThanEqual(-42)), FutureWithin(vec![Constraint::new(1, 2, LessThanEqual(-1))])])),
+        ("negative_assign_and_shift", case(3, true, vec![Future, Assign(1, -5), Shift(2, -3), restrict(1, 0, LessThanEqual(2))])),
+        (
+            "largest_dimension",
+            case(256, false, vec![restrict(255, 0, LessThanEqual(10)), restrict(0, 255, LessThan(-3)), Assign(128, 7), Copy(254, 128), restrict(255, 254, LessThanEqual(1))]),
+        ),
+        ("largest_dimension_zero", case(256, true, vec![Future, Free(200), Shift(255, 5), CloseClock(255), restrict(255, 200, LessThan(2))])),
+        ("largest_dimension_overflow", case(256, false, vec![Assign(255, 40), Shift(255, 3), restrict(1, 255, LessThanEqual(-40))])),
+        ("largest_dimension_out_of_range", case(256, false, vec![restrict(256, 1, LessThanEqual(2)), CloseIJ(0, 256), CloseClock(256), Free(256), Copy(1, 256)])),
+    ];
+}
+
+/// Named pairs of sequences for the `pairs` target. The second zone starts from the opposite of
+/// the first.
+pub fn pair_seeds() -> Vec<(&'static str, Case, Case)> {
+    use Bound::{LessThan, LessThanEqual};
+    use Operation::*;
+    return vec![
+        ("zero_and_future", case(10, true, vec![]), case(10, false, vec![restrict(1, 0, LessThanEqual(0)), Future])),
+        (
+            "convex_hull_keeps_diagonals",
+            case(3, true, vec![Future, restrict(1, 0, LessThanEqual(2))]),
+            case(3, false, vec![Assign(1, 4), Assign(2, 4), Future, restrict(1, 0, LessThanEqual(6))]),
+        ),
+        (
+            "intersect_disjoint",
+            case(4, false, vec![restrict(1, 0, LessThan(3))]),
+            case(4, true, vec![Future, restrict(0, 1, LessThanEqual(-3))]),
+        ),
+        (
+            "relation_different",
+            case(4, true, vec![Future, restrict(1, 0, LessThanEqual(5))]),
+            case(4, false, vec![restrict(0, 2, LessThanEqual(-1)), restrict(2, 0, LessThanEqual(7))]),
+        ),
+        (
+            "intersect_overflows",
+            case(3, false, vec![restrict(1, 0, LessThanEqual(42))]),
+            case(3, true, vec![Future, restrict(2, 1, LessThanEqual(42))]),
+        ),
+        (
+            "largest_dimension",
+            case(256, true, vec![Future, restrict(255, 0, LessThanEqual(5))]),
+            case(256, false, vec![restrict(0, 255, LessThanEqual(-3)), restrict(128, 255, LessThan(0))]),
+        ),
+    ];
+}
@@ -0,0 +1,41 @@
+//Replays the corpus on stable, without libFuzzer, so that the seeds and any inputs the fuzzer has saved are checked by a
+//plain cargo test.
+
+use dbm_baenk_fuzz::{check_operations, check_pair, decode, decode_pair, encode, encode_pair, pair_seeds, seeds};
+use std::fs;
+use std::path::Path;
+
+fn corpus(target: &str) -> Vec<Vec<u8>> {
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus").join(target);
+    let mut entries: Vec<_> = fs::read_dir(directory).expect("the corpus is seeded").map(|entry| entry.unwrap().path()).collect();
+    entries.sort();
+    return entries.into_iter().map(|path| fs::read(path).unwrap()).collect();
+}
+
+#[test]
+fn test_seeds_round_trip() {
+    for (name, case) in seeds() {
+        assert_eq!(decode(&encode(&case)), Some(case), "{}", name);
+    }
+    for (name, first, second) in pair_seeds() {
+        assert_eq!(decode_pair(&encode_pair(&first, &second)), Some((first, second)), "{}", name);
+    }
+}
+
+#[test]
+fn test_corpus_contains_seeds() {
+    let operations = corpus("operations");
+    assert!(seeds().iter().all(|(_, case)| operations.contains(&encode(case))));
+    let pairs = corpus("pairs");
+    assert!(pair_seeds().iter().all(|(_, first, second)| pairs.contains(&encode_pair(first, second))));
+}
+
+#[test]
+fn test_replay_operations() {
+    corpus("operations").iter().for_each(|data| check_operations(data));
+}
+
+#[test]
+fn test_replay_pairs() {
+    corpus("pairs").iter().for_each(|data| check_pair(data));
+}
@@ -0,0 +1,82 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Neg};
+
+/// An upper bound `x_i - x_j < c`, `x_i - x_j <= c` or `x_i - x_j < inf` on the difference of two
+/// clocks, independent of how a backend encodes it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Bound<T> {
+    LessThan(T),
+    LessThanEqual(T),
+    Infinity,
+}
+
+impl<T> Bound<T> {
+    pub fn is_strict(&self) -> bool {
+        return !matches!(self, Bound::LessThanEqual(_));
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        return matches!(self, Bound::Infinity);
+    }
+
+    /// The constant of a finite bound, or `None` for infinity.
+    pub fn constant(&self) -> Option<&T> {
+        return match self {
+            Bound::LessThan(constant) | Bound::LessThanEqual(constant) => Some(constant),
+            Bound::Infinity => None,
+        };
+    }
+}
+
+/// Bounds are ordered by how many differences they admit: `< c` is tighter than `<= c`, which is
+/// tighter than `< c + 1`, and everything is tighter than infinity.
+impl<T: Ord> Ord for Bound<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return match (self.constant(), other.constant()) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+            (Some(lhs), Some(rhs)) => lhs
+                .cmp(rhs)
+                .then_with(|| other.is_strict().cmp(&self.is_strict())),
+        };
+    }
+}
+
+impl<T: Ord> PartialOrd for Bound<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/// The bound on `x_i - x_k` implied by a bound on `x_i - x_j` and one on `x_j - x_k`. The sum is
+/// strict if either bound is, and infinite if either bound is.
+impl<T: Add<Output = T>> Add for Bound<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        return match (self, other) {
+            (Bound::LessThanEqual(lhs), Bound::LessThanEqual(rhs)) => Bound::LessThanEqual(lhs + rhs),
+            (Bound::LessThan(lhs), Bound::LessThan(rhs))
+            | (Bound::LessThan(lhs), Bound::LessThanEqual(rhs))
+            | (Bound::LessThanEqual(lhs), Bound::LessThan(rhs)) => Bound::LessThan(lhs + rhs),
+            _ => Bound::Infinity,
+        };
+    }
+}
+
+/// The complement of a bound, seen from the other clock: the negation of `x_i - x_j < c` is
+/// `x_j - x_i <= -c`, and that of `x_i - x_j <= c` is `x_j - x_i < -c`.
+///
+/// Panics on infinity, since no difference violates it.
+impl<T: Neg<Output = T>> Neg for Bound<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        return match self {
+            Bound::LessThan(constant) => Bound::LessThanEqual(-constant),
+            Bound::LessThanEqual(constant) => Bound::LessThan(-constant),
+            Bound::Infinity => panic!("the negation of an infinite bound is unsatisfiable"),
+        };
+    }
+}
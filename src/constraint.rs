@@ -0,0 +1,69 @@
+use crate::{Bound, DBM};
+use num::Zero;
+
+/// The clock constraint `x_i - x_j < c` or `x_i - x_j <= c`, as used in guards and invariants.
+/// Constraints on a single clock use the reference clock 0 as `i` or `j`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Constraint<T> {
+    pub i: usize,
+    pub j: usize,
+    pub bound: Bound<T>,
+}
+
+impl<T> Constraint<T> {
+    pub fn new(i: usize, j: usize, bound: Bound<T>) -> Self {
+        return Constraint { i, j, bound };
+    }
+}
+
+/// The fewest constraints that describe the zone, following the reduction of Larsen et al.,
+/// "Efficient verification of real-time systems: compact data structure and state-space
+/// reduction". Clocks whose differences are fixed are grouped into classes joined by a single
+/// cycle of constraints, and a constraint between classes is kept only if no path through a
+/// third class implies it. Constraints `x_0 - x_j <= 0` hold for every zone and are left out, so
+/// restricting `init` by the result rebuilds the zone. An empty zone yields a single
+/// contradicting constraint.
+pub fn minimal_constraints<T, D>(dbm: &D) -> impl Iterator<Item = Constraint<T>>
+where
+    T: Ord + Clone + Zero + std::ops::Add<Output = T>,
+    D: DBM<T>,
+{
+    let dim = D::dimension(dbm);
+    if D::is_empty(dbm) {
+        return vec![Constraint::new(1, 0, Bound::LessThan(T::zero()))].into_iter();
+    }
+    let matrix = D::to_matrix(dbm);
+    let zero = Bound::LessThanEqual(T::zero());
+    //representative[i] is the smallest clock whose difference to x_i is fixed
+    let representative: Vec<usize> = (0..dim)
+        .map(|i| {
+            (0..i)
+                .find(|&j| matrix[i][j].clone() + matrix[j][i].clone() == zero)
+                .unwrap_or(i)
+        })
+        .collect();
+    let representatives: Vec<usize> = (0..dim).filter(|&i| representative[i] == i).collect();
+    let mut constraints = Vec::new();
+    for &class in representatives.iter() {
+        let members: Vec<usize> = (class..dim).filter(|&i| representative[i] == class).collect();
+        if members.len() > 1 {
+            for (k, &i) in members.iter().enumerate() {
+                let j = members[(k + 1) % members.len()];
+                constraints.push(Constraint::new(i, j, matrix[i][j].clone()));
+            }
+        }
+    }
+    for &i in representatives.iter() {
+        for &j in representatives.iter().filter(|&&j| j != i) {
+            let implied = representatives
+                .iter()
+                .filter(|&&k| k != i && k != j)
+                .any(|&k| matrix[i][k].clone() + matrix[k][j].clone() <= matrix[i][j]);
+            if !matrix[i][j].is_infinity() && !implied {
+                constraints.push(Constraint::new(i, j, matrix[i][j].clone()));
+            }
+        }
+    }
+    constraints.retain(|constraint| constraint.i != 0 || constraint.bound != zero);
+    return constraints.into_iter();
+}
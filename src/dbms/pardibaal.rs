@@ -1 +1,142 @@
-pub use self::pardibaal
+pub use self::pardibaal::DBM as PARDIBAAL;
+use crate::error::{check_clock, check_dimension, check_dimensions};
+use crate::overflow::{check_bound, check_restrict, check_update, third_of_range};
+use crate::{Bound, DbmError, Relation, DBM};
+use pardibaal::pardibaal;
+
+fn make_bound(bound: Bound<i32>) -> pardibaal::Bound {
+    return match bound {
+        Bound::LessThan(constant) => pardibaal::Bound::strict(constant),
+        Bound::LessThanEqual(constant) => pardibaal::Bound::non_strict(constant),
+        Bound::Infinity => pardibaal::Bound::inf(),
+    };
+}
+
+fn to_bound(bound: pardibaal::Bound) -> Bound<i32> {
+    if bound.is_inf() {
+        return Bound::Infinity;
+    }
+    return match bound.is_strict() {
+        true => Bound::LessThan(bound.get_bound()),
+        false => Bound::LessThanEqual(bound.get_bound()),
+    };
+}
+
+//pardibaal adds bounds without checking for overflow, so constants are kept to a third of the range of i32
+fn max_constant() -> i64 {
+    return third_of_range::<i32>();
+}
+
+// Like udbm, pardibaal does not report errors, so indices are checked before every call.
+impl DBM<i32> for PARDIBAAL {
+    fn try_init(dim: usize) -> Result<PARDIBAAL, DbmError> {
+        check_dimension(dim)?;
+        return Ok(pardibaal::unconstrained(dim));
+    }
+
+    fn try_zero(dim: usize) -> Result<PARDIBAAL, DbmError> {
+        check_dimension(dim)?;
+        return Ok(pardibaal::zero(dim));
+    }
+
+    fn dimension(dbm: &PARDIBAAL) -> usize {
+        return pardibaal::dimension(dbm);
+    }
+
+    fn is_empty(dbm: &PARDIBAAL) -> bool {
+        return pardibaal::is_empty(dbm);
+    }
+
+    fn max_constant() -> i64 {
+        return max_constant();
+    }
+
+    fn try_get_bound(dbm: &PARDIBAAL, i: usize, j: usize) -> Result<Bound<i32>, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        return Ok(to_bound(pardibaal::at(dbm, i, j)));
+    }
+
+    fn try_is_included_in(lhs: &PARDIBAAL, rhs: &PARDIBAAL) -> Result<bool, DbmError> {
+        check_dimensions(Self::dimension(lhs), Self::dimension(rhs))?;
+        if Self::is_empty(lhs) || Self::is_empty(rhs) {
+            return Ok(Self::is_empty(lhs));
+        }
+        return Ok(pardibaal::is_subset(lhs, rhs));
+    }
+
+    fn try_is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<i32>) -> Result<bool, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_bound(&bound, max_constant())?;
+        if bound.is_infinity() {
+            return Ok(!pardibaal::is_empty(dbm));
+        }
+        return Ok(pardibaal::is_satisfied(dbm, i, j, make_bound(bound)));
+    }
+
+    fn try_close(dbm: &mut Self) -> Result<bool, DbmError> {
+        pardibaal::close(dbm);
+        return Ok(!pardibaal::is_empty(dbm));
+    }
+
+    fn try_future(dbm: &mut Self) -> Result<(), DbmError> {
+        pardibaal::future(dbm);
+        return Ok(());
+    }
+
+    fn try_past(dbm: &mut Self) -> Result<(), DbmError> {
+        pardibaal::past(dbm);
+        return Ok(());
+    }
+
+    fn try_restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<i32>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_restrict(dbm, i, j, &bound, max_constant())?;
+        return Self::try_restrict_unchecked(dbm, i, j, bound);
+    }
+
+    fn try_restrict_unchecked(dbm: &mut Self, i: usize, j: usize, bound: Bound<i32>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        pardibaal::restrict(dbm, i, j, make_bound(bound));
+        return Ok(());
+    }
+
+    fn try_free(dbm: &mut Self, clock: usize) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        pardibaal::free(dbm, clock);
+        return Ok(());
+    }
+
+    fn try_assign(dbm: &mut Self, clock: usize, constant: i32) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock, 0, &constant, max_constant())?;
+        pardibaal::assign(dbm, clock, constant);
+        return Ok(());
+    }
+
+    fn try_copy(dbm: &mut Self, clock_to: usize, clock_from: usize) -> Result<(), DbmError> {
+        check_clock(clock_to, Self::dimension(dbm))?;
+        check_clock(clock_from, Self::dimension(dbm))?;
+        pardibaal::copy(dbm, clock_to, clock_from);
+        return Ok(());
+    }
+
+    fn try_shift(dbm: &mut Self, clock: usize, shift_constant: i32) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock, clock, &shift_constant, max_constant())?;
+        pardibaal::shift(dbm, clock, shift_constant);
+        return Ok(());
+    }
+
+    fn try_relation(lhs: &Self, rhs: &Self) -> Result<Relation, DbmError> {
+        check_dimensions(Self::dimension(lhs), Self::dimension(rhs))?;
+        if let Some(relation) = Relation::of_empty(Self::is_empty(lhs), Self::is_empty(rhs)) {
+            return Ok(relation);
+        }
+        let relation = pardibaal::relation(lhs, rhs);
+        return Ok(Relation::from_inclusions(relation.is_subset(), relation.is_superset()));
+    }
+}
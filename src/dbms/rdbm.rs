@@ -1,65 +1,131 @@
 pub use self::rdbm::DBM as RDBM;
-use crate::DBM;
+use crate::error::{check_clock, check_dimension, check_dimensions, from_backend};
+use crate::overflow::{check_bound, check_restrict, check_update, third_of_range};
+use crate::{Bound, DbmError, DBM};
 use rdbm::rdbm;
 
 use num::Bounded;
 use num::Zero;
 
-impl<T: std::ops::Neg<Output = T> + Zero + Bounded + Clone + Ord + num::Saturating> DBM<T>
+//rdbm has no operator for infinite bounds, so those are handled before reaching it
+fn constraint<T>(bound: Bound<T>) -> Option<(rdbm::ConstraintOp, T)> {
+    return match bound {
+        Bound::LessThan(constant) => Some((rdbm::ConstraintOp::LessThan, constant)),
+        Bound::LessThanEqual(constant) => Some((rdbm::ConstraintOp::LessThanEqual, constant)),
+        Bound::Infinity => None,
+    };
+}
+
+fn to_bound<T: Bounded + PartialEq>((op, constant): (rdbm::ConstraintOp, T)) -> Bound<T> {
+    if constant == T::max_value() {
+        return Bound::Infinity;
+    }
+    return match op {
+        rdbm::ConstraintOp::LessThan => Bound::LessThan(constant),
+        rdbm::ConstraintOp::LessThanEqual => Bound::LessThanEqual(constant),
+    };
+}
+
+//rdbm saturates at the largest value of T and reads it as infinity, so constants are kept to a third of the
+//range before they reach it, where no sum in the closure saturates
+impl<T: std::ops::Neg<Output = T> + Zero + Bounded + Clone + Ord + num::Saturating + Into<i64>> DBM<T>
     for RDBM<T>
 {
-    fn init(dim: usize) -> Self {
-        return rdbm::DBM::new(dim);
+    fn try_init(dim: usize) -> Result<Self, DbmError> {
+        check_dimension(dim)?;
+        return Ok(rdbm::DBM::new(dim));
+    }
+
+    fn try_zero(dim: usize) -> Result<Self, DbmError> {
+        check_dimension(dim)?;
+        return Ok(rdbm::DBM::zero(dim));
+    }
+
+    fn dimension(dbm: &Self) -> usize {
+        return rdbm::DBM::get_dimension(dbm);
+    }
+
+    fn is_empty(dbm: &Self) -> bool {
+        return rdbm::DBM::is_empty(dbm);
+    }
+
+    fn max_constant() -> i64 {
+        return third_of_range::<T>();
     }
 
-    fn zero(dim: usize) -> Self {
-        return rdbm::DBM::zero(dim);
+    fn try_get_bound(dbm: &Self, i: usize, j: usize) -> Result<Bound<T>, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        return rdbm::DBM::get_bound(dbm, i, j).map(to_bound).map_err(from_backend(&[i, j], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn is_included_in(lhs: &Self, rhs: &Self) -> bool {
-        return rdbm::DBM::is_included_in(lhs, rhs);
+    fn try_is_included_in(lhs: &Self, rhs: &Self) -> Result<bool, DbmError> {
+        check_dimensions(Self::dimension(lhs), Self::dimension(rhs))?;
+        if Self::is_empty(lhs) || Self::is_empty(rhs) {
+            return Ok(Self::is_empty(lhs));
+        }
+        return Ok(rdbm::DBM::is_included_in(lhs, rhs));
     }
-    fn is_satisfied(dbm: &Self, i: usize, j: usize, is_bound_strict: bool, constant: T) -> bool {
-        let constraint_op = match is_bound_strict {
-            true => rdbm::ConstraintOp::LessThan,
-            false => rdbm::ConstraintOp::LessThanEqual,
+
+    fn try_is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<T>) -> Result<bool, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_bound(&bound, third_of_range::<T>())?;
+        return match constraint(bound) {
+            Some((op, constant)) => rdbm::DBM::satisfied(dbm, i, j, op, constant).map_err(from_backend(&[i, j], Self::dimension(dbm), Self::is_empty(dbm))),
+            None => Ok(!rdbm::DBM::is_empty(dbm)),
         };
-        return rdbm::DBM::satisfied(dbm, i, j, constraint_op, constant).unwrap();
     }
 
-    fn close(dbm: &mut Self) {
+    fn try_close(dbm: &mut Self) -> Result<bool, DbmError> {
         rdbm::DBM::close(dbm);
+        return Ok(!rdbm::DBM::is_empty(dbm));
+    }
+
+    fn try_future(dbm: &mut Self) -> Result<(), DbmError> {
+        return rdbm::DBM::up(dbm).map_err(from_backend(&[], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn future(dbm: &mut Self) {
-        rdbm::DBM::up(dbm).unwrap();
+    fn try_past(dbm: &mut Self) -> Result<(), DbmError> {
+        return rdbm::DBM::down(dbm).map_err(from_backend(&[], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn past(dbm: &mut Self) {
-        rdbm::DBM::down(dbm).unwrap();
+    fn try_restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<T>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_restrict(dbm, i, j, &bound, third_of_range::<T>())?;
+        return Self::try_restrict_unchecked(dbm, i, j, bound);
     }
 
-    fn restrict(dbm: &mut Self, i: usize, j: usize, bound_is_strict: bool, constant: T) {
-        let constraint_op = match bound_is_strict {
-            true => rdbm::ConstraintOp::LessThan,
-            false => rdbm::ConstraintOp::LessThanEqual,
+    fn try_restrict_unchecked(dbm: &mut Self, i: usize, j: usize, bound: Bound<T>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        return match constraint(bound) {
+            Some((op, constant)) => rdbm::DBM::and(dbm, i, j, op, constant).map_err(from_backend(&[i, j], Self::dimension(dbm), Self::is_empty(dbm))),
+            None => Ok(()),
         };
-        rdbm::DBM::and(dbm, i, j, constraint_op, constant).unwrap();
     }
 
-    fn free(dbm: &mut Self, clock: usize) {
-        rdbm::DBM::free(dbm, clock).unwrap();
+    fn try_free(dbm: &mut Self, clock: usize) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        return rdbm::DBM::free(dbm, clock).map_err(from_backend(&[clock], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn assign(dbm: &mut Self, clock: usize, constant: T) {
-        rdbm::DBM::reset(dbm, clock, constant).unwrap();
+    fn try_assign(dbm: &mut Self, clock: usize, constant: T) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock, 0, &constant, third_of_range::<T>())?;
+        return rdbm::DBM::reset(dbm, clock, constant).map_err(from_backend(&[clock], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn copy(dbm: &mut Self, clock_to: usize, clock_from: usize) {
-        rdbm::DBM::copy(dbm, clock_to, clock_from).unwrap();
+    fn try_copy(dbm: &mut Self, clock_to: usize, clock_from: usize) -> Result<(), DbmError> {
+        check_clock(clock_to, Self::dimension(dbm))?;
+        check_clock(clock_from, Self::dimension(dbm))?;
+        return rdbm::DBM::copy(dbm, clock_to, clock_from).map_err(from_backend(&[clock_to, clock_from], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn shift(dbm: &mut Self, clock: usize, shift_constant: T) {
-        rdbm::DBM::shift(dbm, clock, shift_constant).unwrap();
+    fn try_shift(dbm: &mut Self, clock: usize, shift_constant: T) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock, clock, &shift_constant, third_of_range::<T>())?;
+        return rdbm::DBM::shift(dbm, clock, shift_constant).map_err(from_backend(&[clock], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 }
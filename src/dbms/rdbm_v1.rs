@@ -1,67 +1,146 @@
 pub use self::rdbm::DBM as RDBM_V1;
-use crate::DBM;
+use crate::error::{check_clock, check_dimension, check_dimensions, from_backend};
+use crate::overflow::{check_bound, check_restrict, check_update, third_of_range};
+use crate::{Bound, DbmError, DBM};
 use rdbm_v1::rdbm;
 
 use num::Bounded;
 use num::Zero;
 
-impl<T: std::ops::Neg<Output = T> + Zero + Bounded + Clone + Ord + num::Saturating> DBM<T>
+//rdbm has no operator for infinite bounds, so those are handled before reaching it
+fn constraint<T>(bound: Bound<T>) -> Option<(rdbm::ConstraintOp, T)> {
+    return match bound {
+        Bound::LessThan(constant) => Some((rdbm::ConstraintOp::LessThan, constant)),
+        Bound::LessThanEqual(constant) => Some((rdbm::ConstraintOp::LessThanEqual, constant)),
+        Bound::Infinity => None,
+    };
+}
+
+//rdbm v1 names clocks by u8, so a DBM holds at most 255 clocks next to the reference clock
+const MAX_DIM: usize = u8::MAX as usize + 1;
+
+fn clocks(dim: usize) -> Result<Vec<u8>, DbmError> {
+    if dim > MAX_DIM {
+        return Err(DbmError::DimensionTooLarge { dim, max: MAX_DIM });
+    }
+    return Ok((1..dim).map(|clock| clock as u8).collect());
+}
+
+fn clock_index(clock: usize, dim: usize) -> Result<u8, DbmError> {
+    check_clock(clock, dim)?;
+    return Ok(clock as u8); //dimensions never exceed MAX_DIM, so this cannot truncate
+}
+
+fn to_bound<T: Bounded + PartialEq>((op, constant): (rdbm::ConstraintOp, T)) -> Bound<T> {
+    if constant == T::max_value() {
+        return Bound::Infinity;
+    }
+    return match op {
+        rdbm::ConstraintOp::LessThan => Bound::LessThan(constant),
+        rdbm::ConstraintOp::LessThanEqual => Bound::LessThanEqual(constant),
+    };
+}
+
+//rdbm saturates at the largest value of T and reads it as infinity, so constants are kept to a third of the
+//range before they reach it, where no sum in the closure saturates
+impl<T: std::ops::Neg<Output = T> + Zero + Bounded + Clone + Ord + num::Saturating + Into<i64>> DBM<T>
     for RDBM_V1<T>
 {
-    fn init(dim: usize) -> Self {
-        let clocks = (1..dim as u8).collect();
-        return rdbm::DBM::new(clocks);
+    fn try_init(dim: usize) -> Result<Self, DbmError> {
+        check_dimension(dim)?;
+        return Ok(rdbm::DBM::new(clocks(dim)?));
     }
 
-    fn zero(dim: usize) -> Self {
-        let clocks = (1..dim as u8).collect();
-        return rdbm::DBM::zero(clocks);
+    fn try_zero(dim: usize) -> Result<Self, DbmError> {
+        check_dimension(dim)?;
+        return Ok(rdbm::DBM::zero(clocks(dim)?));
     }
 
-    fn is_included_in(lhs: &Self, rhs: &Self) -> bool {
-        return rdbm::DBM::is_included_in(lhs, rhs);
+    fn dimension(dbm: &Self) -> usize {
+        return rdbm::DBM::get_dimension(dbm);
     }
-    fn is_satisfied(dbm: &Self, i: usize, j: usize, is_bound_strict: bool, constant: T) -> bool {
-        let constraint_op = match is_bound_strict {
-            true => rdbm::ConstraintOp::LessThan,
-            false => rdbm::ConstraintOp::LessThanEqual,
+
+    fn is_empty(dbm: &Self) -> bool {
+        return rdbm::DBM::is_empty(dbm);
+    }
+
+    fn max_constant() -> i64 {
+        return third_of_range::<T>();
+    }
+
+    fn try_get_bound(dbm: &Self, i: usize, j: usize) -> Result<Bound<T>, DbmError> {
+        let i = clock_index(i, Self::dimension(dbm))?;
+        let j = clock_index(j, Self::dimension(dbm))?;
+        return rdbm::DBM::get_bound(dbm, i, j).map(to_bound).map_err(from_backend(&[i.into(), j.into()], Self::dimension(dbm), Self::is_empty(dbm)));
+    }
+
+    fn try_is_included_in(lhs: &Self, rhs: &Self) -> Result<bool, DbmError> {
+        check_dimensions(Self::dimension(lhs), Self::dimension(rhs))?;
+        if Self::is_empty(lhs) || Self::is_empty(rhs) {
+            return Ok(Self::is_empty(lhs));
+        }
+        return Ok(rdbm::DBM::is_included_in(lhs, rhs));
+    }
+
+    fn try_is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<T>) -> Result<bool, DbmError> {
+        let i = clock_index(i, Self::dimension(dbm))?;
+        let j = clock_index(j, Self::dimension(dbm))?;
+        check_bound(&bound, third_of_range::<T>())?;
+        return match constraint(bound) {
+            Some((op, constant)) => rdbm::DBM::satisfied(dbm, i, j, op, constant).map_err(from_backend(&[i.into(), j.into()], Self::dimension(dbm), Self::is_empty(dbm))),
+            None => Ok(!rdbm::DBM::is_empty(dbm)),
         };
-        return rdbm::DBM::satisfied(dbm, i as u8, j as u8, constraint_op, constant).unwrap();
     }
 
-    fn close(dbm: &mut Self) {
+    fn try_close(dbm: &mut Self) -> Result<bool, DbmError> {
         rdbm::DBM::close(dbm);
+        return Ok(!rdbm::DBM::is_empty(dbm));
+    }
+
+    fn try_future(dbm: &mut Self) -> Result<(), DbmError> {
+        return rdbm::DBM::up(dbm).map_err(from_backend(&[], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn future(dbm: &mut Self) {
-        rdbm::DBM::up(dbm).unwrap();
+    fn try_past(dbm: &mut Self) -> Result<(), DbmError> {
+        return rdbm::DBM::down(dbm).map_err(from_backend(&[], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn past(dbm: &mut Self) {
-        rdbm::DBM::down(dbm).unwrap();
+    fn try_restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<T>) -> Result<(), DbmError> {
+        let i = clock_index(i, Self::dimension(dbm))?;
+        let j = clock_index(j, Self::dimension(dbm))?;
+        check_restrict(dbm, i.into(), j.into(), &bound, third_of_range::<T>())?;
+        return Self::try_restrict_unchecked(dbm, i.into(), j.into(), bound);
     }
 
-    fn restrict(dbm: &mut Self, i: usize, j: usize, bound_is_strict: bool, constant: T) {
-        let constraint_op = match bound_is_strict {
-            true => rdbm::ConstraintOp::LessThan,
-            false => rdbm::ConstraintOp::LessThanEqual,
+    fn try_restrict_unchecked(dbm: &mut Self, i: usize, j: usize, bound: Bound<T>) -> Result<(), DbmError> {
+        let i = clock_index(i, Self::dimension(dbm))?;
+        let j = clock_index(j, Self::dimension(dbm))?;
+        return match constraint(bound) {
+            Some((op, constant)) => rdbm::DBM::and(dbm, i, j, op, constant).map_err(from_backend(&[i.into(), j.into()], Self::dimension(dbm), Self::is_empty(dbm))),
+            None => Ok(()),
         };
-        rdbm::DBM::and(dbm, i as u8, j as u8, constraint_op, constant).unwrap();
     }
 
-    fn free(dbm: &mut Self, clock: usize) {
-        rdbm::DBM::free(dbm, clock as u8).unwrap();
+    fn try_free(dbm: &mut Self, clock: usize) -> Result<(), DbmError> {
+        let clock = clock_index(clock, Self::dimension(dbm))?;
+        return rdbm::DBM::free(dbm, clock).map_err(from_backend(&[clock.into()], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn assign(dbm: &mut Self, clock: usize, constant: T) {
-        rdbm::DBM::reset(dbm, clock as u8, constant).unwrap();
+    fn try_assign(dbm: &mut Self, clock: usize, constant: T) -> Result<(), DbmError> {
+        let clock = clock_index(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock.into(), 0, &constant, third_of_range::<T>())?;
+        return rdbm::DBM::reset(dbm, clock, constant).map_err(from_backend(&[clock.into()], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn copy(dbm: &mut Self, clock_to: usize, clock_from: usize) {
-        rdbm::DBM::copy(dbm, clock_to as u8, clock_from as u8).unwrap();
+    fn try_copy(dbm: &mut Self, clock_to: usize, clock_from: usize) -> Result<(), DbmError> {
+        let clock_to = clock_index(clock_to, Self::dimension(dbm))?;
+        let clock_from = clock_index(clock_from, Self::dimension(dbm))?;
+        return rdbm::DBM::copy(dbm, clock_to, clock_from).map_err(from_backend(&[clock_to.into(), clock_from.into()], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 
-    fn shift(dbm: &mut Self, clock: usize, shift_constant: T) {
-        rdbm::DBM::shift(dbm, clock as u8, shift_constant).unwrap();
+    fn try_shift(dbm: &mut Self, clock: usize, shift_constant: T) -> Result<(), DbmError> {
+        let clock = clock_index(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock.into(), clock.into(), &shift_constant, third_of_range::<T>())?;
+        return rdbm::DBM::shift(dbm, clock, shift_constant).map_err(from_backend(&[clock.into()], Self::dimension(dbm), Self::is_empty(dbm)));
     }
 }
@@ -0,0 +1,257 @@
+use crate::error::{check_clock, check_dimension, check_dimensions};
+use crate::overflow::{check_bound, check_constant, third_of_range};
+use crate::{Bound, DbmError, DBM};
+
+/// A deliberately naive DBM: a square matrix of bounds, closed with textbook Floyd–Warshall
+/// after every operation. It is slow on purpose and serves as the oracle in the differential
+/// tests and as the baseline in the benchmarks.
+///
+/// Constants are 64-bit, but the matrix holds them in 128 bits so that closing never overflows.
+/// Every operation is computed on a copy, which replaces the DBM only if all its bounds are
+/// within the limit.
+#[derive(Clone, Debug)]
+pub struct REFERENCE {
+    bounds: Vec<Vec<Bound<i128>>>,
+}
+
+//closing in 128 bits cannot overflow, so the limit only keeps bounds within i64, at the limit rdbm would have for i64
+fn max_constant() -> i64 {
+    return third_of_range::<i64>();
+}
+
+fn widen(bound: Bound<i64>) -> Bound<i128> {
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(constant.into()),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(constant.into()),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+//bounds in the matrix are within the limit, so they always fit back into 64 bits
+fn narrow(bound: Bound<i128>) -> Bound<i64> {
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(constant as i64),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(constant as i64),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+impl REFERENCE {
+    fn filled(dim: usize, bound: Bound<i128>) -> Self {
+        let mut bounds = vec![vec![bound; dim]; dim];
+        for (i, row) in bounds.iter_mut().enumerate() {
+            row[i] = Bound::LessThanEqual(0);
+        }
+        return REFERENCE { bounds };
+    }
+
+    //every clock is bounded below by zero
+    fn bound_clocks_below(&mut self) {
+        for j in 0..self.bounds.len() {
+            self.bounds[0][j] = self.bounds[0][j].min(Bound::LessThanEqual(0));
+        }
+    }
+
+    fn tighten(&mut self, i: usize, j: usize, bound: Bound<i128>) {
+        self.bounds[i][j] = self.bounds[i][j].min(bound);
+    }
+
+    fn close_bounds(&mut self) {
+        let dim = self.bounds.len();
+        for k in 0..dim {
+            for i in 0..dim {
+                for j in 0..dim {
+                    let through_k = self.bounds[i][k] + self.bounds[k][j];
+                    self.tighten(i, j, through_k);
+                }
+            }
+        }
+    }
+
+    //closes the changed copy and puts it in place of the DBM, unless it has a bound beyond the limit
+    fn commit(dbm: &mut Self, mut changed: Self) -> Result<(), DbmError> {
+        changed.close_bounds();
+        if !Self::is_empty(&changed) {
+            for constant in changed.bounds.iter().flatten().filter_map(|bound| bound.constant()) {
+                check_constant(*constant, max_constant())?;
+            }
+        }
+        *dbm = changed;
+        return Ok(());
+    }
+}
+
+// Operations on an empty zone leave it empty, since no valuation is there to be changed.
+impl DBM<i64> for REFERENCE {
+    fn try_init(dim: usize) -> Result<Self, DbmError> {
+        check_dimension(dim)?;
+        let mut dbm = REFERENCE::filled(dim, Bound::Infinity);
+        dbm.bound_clocks_below();
+        return Ok(dbm);
+    }
+
+    fn try_zero(dim: usize) -> Result<Self, DbmError> {
+        check_dimension(dim)?;
+        return Ok(REFERENCE::filled(dim, Bound::LessThanEqual(0)));
+    }
+
+    fn dimension(dbm: &Self) -> usize {
+        return dbm.bounds.len();
+    }
+
+    fn is_empty(dbm: &Self) -> bool {
+        return (0..dbm.bounds.len()).any(|i| dbm.bounds[i][i] < Bound::LessThanEqual(0));
+    }
+
+    fn max_constant() -> i64 {
+        return max_constant();
+    }
+
+    fn try_get_bound(dbm: &Self, i: usize, j: usize) -> Result<Bound<i64>, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        return Ok(narrow(dbm.bounds[i][j]));
+    }
+
+    fn try_is_included_in(lhs: &Self, rhs: &Self) -> Result<bool, DbmError> {
+        check_dimensions(Self::dimension(lhs), Self::dimension(rhs))?;
+        if Self::is_empty(lhs) || Self::is_empty(rhs) {
+            return Ok(Self::is_empty(lhs));
+        }
+        return Ok(lhs.bounds.iter().zip(rhs.bounds.iter()).all(|(lhs_row, rhs_row)| {
+            lhs_row.iter().zip(rhs_row.iter()).all(|(lhs_bound, rhs_bound)| lhs_bound <= rhs_bound)
+        }));
+    }
+
+    fn try_is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<i64>) -> Result<bool, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_bound(&bound, max_constant())?;
+        if Self::is_empty(dbm) {
+            return Ok(false);
+        }
+        //restricting would close a negative cycle through x_j - x_i
+        return Ok(widen(bound) + dbm.bounds[j][i] >= Bound::LessThanEqual(0));
+    }
+
+    fn try_close(dbm: &mut Self) -> Result<bool, DbmError> {
+        dbm.close_bounds();
+        return Ok(!Self::is_empty(dbm));
+    }
+
+    fn try_future(dbm: &mut Self) -> Result<(), DbmError> {
+        if Self::is_empty(dbm) {
+            return Ok(());
+        }
+        let mut delayed = dbm.clone();
+        for i in 1..Self::dimension(dbm) {
+            delayed.bounds[i][0] = Bound::Infinity;
+        }
+        return Self::commit(dbm, delayed);
+    }
+
+    fn try_past(dbm: &mut Self) -> Result<(), DbmError> {
+        if Self::is_empty(dbm) {
+            return Ok(());
+        }
+        //a clock can go back to zero, unless a clock it is bounded below by reaches zero first
+        let dim = Self::dimension(dbm);
+        let mut delayed = dbm.clone();
+        for j in 1..dim {
+            delayed.bounds[0][j] = Bound::LessThanEqual(0);
+            for i in 1..dim {
+                delayed.tighten(0, j, dbm.bounds[i][j]);
+            }
+        }
+        return Self::commit(dbm, delayed);
+    }
+
+    fn try_restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<i64>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_bound(&bound, max_constant())?;
+        if Self::is_empty(dbm) {
+            return Ok(());
+        }
+        let mut restricted = dbm.clone();
+        restricted.tighten(i, j, widen(bound));
+        return Self::commit(dbm, restricted);
+    }
+
+    fn try_restrict_unchecked(dbm: &mut Self, i: usize, j: usize, bound: Bound<i64>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        if Self::is_empty(dbm) {
+            return Ok(());
+        }
+        dbm.tighten(i, j, widen(bound));
+        dbm.close_bounds();
+        return Ok(());
+    }
+
+    //every bound is tightened before the matrix is closed once, rather than closing after each of them
+    fn try_from_matrix(matrix: Vec<Vec<Bound<i64>>>) -> Result<Self, DbmError> {
+        let dim = matrix.len();
+        let mut dbm = Self::try_init(dim)?;
+        let mut built = dbm.clone();
+        for (i, row) in matrix.into_iter().enumerate() {
+            check_dimensions(dim, row.len())?;
+            for (j, bound) in row.into_iter().enumerate().filter(|&(j, _)| j != i) {
+                check_bound(&bound, max_constant())?;
+                built.tighten(i, j, widen(bound));
+            }
+        }
+        Self::commit(&mut dbm, built)?;
+        return Ok(dbm);
+    }
+
+    fn try_free(dbm: &mut Self, clock: usize) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        if Self::is_empty(dbm) {
+            return Ok(());
+        }
+        let mut freed = dbm.clone();
+        for i in (0..Self::dimension(dbm)).filter(|&i| i != clock) {
+            freed.bounds[clock][i] = Bound::Infinity;
+            freed.bounds[i][clock] = dbm.bounds[i][0];
+        }
+        return Self::commit(dbm, freed);
+    }
+
+    fn try_assign(dbm: &mut Self, clock: usize, constant: i64) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_constant(constant.into(), max_constant())?;
+        let mut assigned = dbm.clone();
+        Self::try_free(&mut assigned, clock)?;
+        Self::try_restrict(&mut assigned, clock, 0, Bound::LessThanEqual(constant))?;
+        Self::try_restrict(&mut assigned, 0, clock, Bound::LessThanEqual(-constant))?;
+        *dbm = assigned;
+        return Ok(());
+    }
+
+    fn try_copy(dbm: &mut Self, clock_to: usize, clock_from: usize) -> Result<(), DbmError> {
+        check_clock(clock_to, Self::dimension(dbm))?;
+        check_clock(clock_from, Self::dimension(dbm))?;
+        if clock_to == clock_from {
+            return Ok(());
+        }
+        Self::try_free(dbm, clock_to)?;
+        Self::try_restrict(dbm, clock_to, clock_from, Bound::LessThanEqual(0))?;
+        return Self::try_restrict(dbm, clock_from, clock_to, Bound::LessThanEqual(0));
+    }
+
+    fn try_shift(dbm: &mut Self, clock: usize, shift_constant: i64) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_constant(shift_constant.into(), max_constant())?;
+        if Self::is_empty(dbm) {
+            return Ok(());
+        }
+        let shift_constant = i128::from(shift_constant);
+        let mut shifted = dbm.clone();
+        for i in (0..Self::dimension(dbm)).filter(|&i| i != clock) {
+            shifted.bounds[clock][i] = dbm.bounds[clock][i] + Bound::LessThanEqual(shift_constant);
+            shifted.bounds[i][clock] = dbm.bounds[i][clock] + Bound::LessThanEqual(-shift_constant);
+        }
+        return Self::commit(dbm, shifted);
+    }
+}
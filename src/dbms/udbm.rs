@@ -1,52 +1,248 @@
 pub use self::udbm::DBM as UDBM;
-use crate::DBM;
+use crate::error::{check_clock, check_constraints, check_dimension, check_dimensions};
+use crate::overflow::{check_all, check_bound, check_restrict, check_update};
+use crate::{Bound, Constraint, DbmError, Relation, DBM};
 use udbm_rs::udbm;
 
+const LS_INFINITY: i32 = (i32::MAX >> 1) << 1; //dbm_LS_INFINITY, the raw encoding of < infinity
+
+/// The largest constant udbm accepts. udbm adds up to three raw bounds, each twice the constant
+/// plus one, when it closes a DBM after a restriction, so raw bounds are kept to a third of the
+/// range below `LS_INFINITY`.
+pub(crate) const MAX_CONSTANT: i64 = (LS_INFINITY as i64 / 3 - 1) >> 1;
+
+//inverse of udbm::encode_bound: the constant is stored shifted left by one, with the lowest bit set for <=
+fn decode_bound(raw: i32) -> Bound<i32> {
+    if raw == LS_INFINITY {
+        return Bound::Infinity;
+    }
+    return match raw & 1 {
+        0 => Bound::LessThan(raw >> 1),
+        _ => Bound::LessThanEqual(raw >> 1),
+    };
+}
+
+//infinity encodes as LS_INFINITY, which udbm treats as no constraint at all
+fn encode_bound(bound: Bound<i32>) -> i32 {
+    return match bound {
+        Bound::LessThan(constant) => udbm::encode_bound(constant, true),
+        Bound::LessThanEqual(constant) => udbm::encode_bound(constant, false),
+        Bound::Infinity => LS_INFINITY,
+    };
+}
+
+//constants within the limit keep every sum of one closure step below LS_INFINITY, so the native operations that apply
+//several constraints at once can run unchecked, and only their result is checked against the limit afterwards
+fn check_constants(constraints: &[Constraint<i32>]) -> Result<(), DbmError> {
+    for constraint in constraints {
+        check_bound(&constraint.bound, MAX_CONSTANT)?;
+    }
+    return Ok(());
+}
+
+fn encode_constraints(constraints: &[Constraint<i32>]) -> Vec<(usize, usize, i32)> {
+    return constraints
+        .iter()
+        .map(|constraint| (constraint.i, constraint.j, encode_bound(constraint.bound)))
+        .collect();
+}
+
+// udbm asserts on its inputs rather than reporting errors, so every index is checked here
+// before it reaches the C library.
 impl DBM<i32> for UDBM {
-    fn init(dim: usize) -> UDBM {
-        return udbm::init(dim);
+    fn try_init(dim: usize) -> Result<UDBM, DbmError> {
+        check_dimension(dim)?;
+        return Ok(udbm::init(dim));
     }
 
-    fn zero(dim: usize) -> UDBM {
-        return udbm::zero(dim);
+    fn try_zero(dim: usize) -> Result<UDBM, DbmError> {
+        check_dimension(dim)?;
+        return Ok(udbm::zero(dim));
     }
 
-    fn is_included_in(lhs: &UDBM, rhs: &UDBM) -> bool {
-        return udbm::is_subset(lhs, rhs);
+    fn dimension(dbm: &UDBM) -> usize {
+        return udbm::get_dimension(dbm);
     }
 
-    fn is_satisfied(dbm: &Self, i: usize, j: usize, bound_is_strict: bool, constant: i32) -> bool {
-        return udbm::satisfies(dbm, i, j, udbm::encode_bound(constant, bound_is_strict));
+    fn is_empty(dbm: &UDBM) -> bool {
+        return udbm::is_empty(dbm);
     }
-    fn close(dbm: &mut Self) {
-        udbm::close(dbm);
+
+    fn max_constant() -> i64 {
+        return MAX_CONSTANT;
+    }
+
+    fn try_get_bound(dbm: &UDBM, i: usize, j: usize) -> Result<Bound<i32>, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        return Ok(decode_bound(udbm::get_bound(dbm, i, j)));
+    }
+
+    fn try_is_included_in(lhs: &UDBM, rhs: &UDBM) -> Result<bool, DbmError> {
+        check_dimensions(Self::dimension(lhs), Self::dimension(rhs))?;
+        if Self::is_empty(lhs) || Self::is_empty(rhs) {
+            return Ok(Self::is_empty(lhs));
+        }
+        return Ok(udbm::is_subset(lhs, rhs));
+    }
+
+    fn try_is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<i32>) -> Result<bool, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_bound(&bound, MAX_CONSTANT)?;
+        if bound.is_infinity() {
+            return Ok(!udbm::is_empty(dbm));
+        }
+        return Ok(udbm::satisfies(dbm, i, j, encode_bound(bound)));
+    }
+
+    fn try_close(dbm: &mut Self) -> Result<bool, DbmError> {
+        return Ok(udbm::close(dbm));
+    }
+
+    fn try_close_ij(dbm: &mut Self, i: usize, j: usize) -> Result<bool, DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        return Ok(udbm::close_ij(dbm, i, j));
+    }
+
+    fn try_close_clock(dbm: &mut Self, clock: usize) -> Result<bool, DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        return Ok(udbm::close1(dbm, clock));
     }
 
-    fn future(dbm: &mut Self) {
+    fn try_future(dbm: &mut Self) -> Result<(), DbmError> {
         udbm::up(dbm);
+        return Ok(());
     }
 
-    fn past(dbm: &mut Self) {
+    fn try_past(dbm: &mut Self) -> Result<(), DbmError> {
         udbm::down(dbm);
+        return Ok(());
     }
 
-    fn restrict(dbm: &mut Self, i: usize, j: usize, bound_is_strict: bool, constant: i32) {
-        udbm::and(dbm, i, j, udbm::encode_bound(constant, bound_is_strict));
+    fn try_restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<i32>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        check_restrict(dbm, i, j, &bound, MAX_CONSTANT)?;
+        return Self::try_restrict_unchecked(dbm, i, j, bound);
     }
 
-    fn free(dbm: &mut Self, clock: usize) {
+    fn try_restrict_unchecked(dbm: &mut Self, i: usize, j: usize, bound: Bound<i32>) -> Result<(), DbmError> {
+        check_clock(i, Self::dimension(dbm))?;
+        check_clock(j, Self::dimension(dbm))?;
+        udbm::and(dbm, i, j, encode_bound(bound));
+        return Ok(());
+    }
+
+    fn try_free(dbm: &mut Self, clock: usize) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
         udbm::free(dbm, clock);
+        return Ok(());
     }
 
-    fn assign(dbm: &mut Self, clock: usize, constant: i32) {
+    fn try_assign(dbm: &mut Self, clock: usize, constant: i32) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock, 0, &constant, MAX_CONSTANT)?;
         udbm::assign(dbm, clock, constant);
+        return Ok(());
     }
 
-    fn copy(dbm: &mut Self, clock_to: usize, clock_from: usize) {
+    fn try_copy(dbm: &mut Self, clock_to: usize, clock_from: usize) -> Result<(), DbmError> {
+        check_clock(clock_to, Self::dimension(dbm))?;
+        check_clock(clock_from, Self::dimension(dbm))?;
         udbm::copy(dbm, clock_to, clock_from);
+        return Ok(());
     }
 
-    fn shift(dbm: &mut Self, clock: usize, shift_constant: i32) {
+    fn try_shift(dbm: &mut Self, clock: usize, shift_constant: i32) -> Result<(), DbmError> {
+        check_clock(clock, Self::dimension(dbm))?;
+        check_update(dbm, clock, clock, &shift_constant, MAX_CONSTANT)?;
         udbm::shift(dbm, clock, shift_constant);
+        return Ok(());
+    }
+
+    fn try_future_within(dbm: &mut Self, invariant: &[Constraint<i32>]) -> Result<(), DbmError> {
+        check_constraints(invariant, Self::dimension(dbm))?;
+        check_constants(invariant)?;
+        udbm::up(dbm);
+        udbm::and_n(dbm, &encode_constraints(invariant));
+        return check_all(dbm, MAX_CONSTANT);
+    }
+
+    fn try_past_within(dbm: &mut Self, guard: &[Constraint<i32>]) -> Result<(), DbmError> {
+        check_constraints(guard, Self::dimension(dbm))?;
+        check_constants(guard)?;
+        udbm::down(dbm);
+        udbm::and_n(dbm, &encode_constraints(guard));
+        return check_all(dbm, MAX_CONSTANT);
+    }
+
+    fn try_update(dbm: &mut Self, clock_to: usize, clock_from: usize, constant: i32) -> Result<(), DbmError> {
+        check_clock(clock_to, Self::dimension(dbm))?;
+        check_clock(clock_from, Self::dimension(dbm))?;
+        check_update(dbm, clock_to, clock_from, &constant, MAX_CONSTANT)?;
+        udbm::update(dbm, clock_to, clock_from, constant);
+        return Ok(());
+    }
+
+    fn try_intersect(dbm: &mut Self, other: &Self) -> Result<(), DbmError> {
+        check_dimensions(Self::dimension(dbm), Self::dimension(other))?;
+        check_all(other, MAX_CONSTANT)?;
+        udbm::intersection(dbm, other);
+        return check_all(dbm, MAX_CONSTANT);
+    }
+
+    fn try_convex_hull(dbm: &mut Self, other: &Self) -> Result<(), DbmError> {
+        check_dimensions(Self::dimension(dbm), Self::dimension(other))?;
+        //udbm expects two non-empty zones
+        if udbm::is_empty(other) {
+            return Ok(());
+        }
+        if udbm::is_empty(dbm) {
+            *dbm = other.clone();
+            return Ok(());
+        }
+        udbm::convex_union(dbm, other);
+        return Ok(());
+    }
+
+    fn try_relation(lhs: &Self, rhs: &Self) -> Result<Relation, DbmError> {
+        check_dimensions(Self::dimension(lhs), Self::dimension(rhs))?;
+        if let Some(relation) = Relation::of_empty(Self::is_empty(lhs), Self::is_empty(rhs)) {
+            return Ok(relation);
+        }
+        return Ok(match udbm::relation(lhs, rhs) {
+            udbm::Relation::Equal => Relation::Equal,
+            udbm::Relation::Subset => Relation::Subset,
+            udbm::Relation::Superset => Relation::Superset,
+            udbm::Relation::Different => Relation::Different,
+        });
+    }
+
+    fn try_extrapolate_max_bounds(dbm: &mut Self, max_bounds: &[i32]) -> Result<(), DbmError> {
+        check_dimensions(Self::dimension(dbm), max_bounds.len())?;
+        udbm::extrapolate_max_bounds(dbm, max_bounds);
+        return Ok(());
+    }
+
+    fn try_extrapolate_diagonal_max_bounds(dbm: &mut Self, max_bounds: &[i32]) -> Result<(), DbmError> {
+        check_dimensions(Self::dimension(dbm), max_bounds.len())?;
+        udbm::diagonal_extrapolate_max_bounds(dbm, max_bounds);
+        return Ok(());
+    }
+
+    fn try_extrapolate_lu_bounds(dbm: &mut Self, lower: &[i32], upper: &[i32]) -> Result<(), DbmError> {
+        check_dimensions(Self::dimension(dbm), lower.len())?;
+        check_dimensions(Self::dimension(dbm), upper.len())?;
+        udbm::extrapolate_lu_bounds(dbm, lower, upper);
+        return Ok(());
+    }
+
+    fn try_extrapolate_diagonal_lu_bounds(dbm: &mut Self, lower: &[i32], upper: &[i32]) -> Result<(), DbmError> {
+        check_dimensions(Self::dimension(dbm), lower.len())?;
+        check_dimensions(Self::dimension(dbm), upper.len())?;
+        udbm::diagonal_extrapolate_lu_bounds(dbm, lower, upper);
+        return Ok(());
     }
 }
@@ -0,0 +1,163 @@
+use crate::dbms::udbm::{MAX_CONSTANT, UDBM};
+use crate::{Bound, Constraint, DbmError, Relation, DBM};
+
+/// UDBM behind a `DBM<i64>` interface, for code that is written against `i64` constants. It is
+/// not a 64-bit backend: udbm is built for 32-bit bounds, so every constant is checked on the way
+/// in and rejected with `DbmError::Overflow` if it is beyond the limit of udbm, exactly the range
+/// `UDBM` accepts. It is therefore left out of the backend matrices of the tests and benchmarks.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug)]
+pub struct UDBM_I64(UDBM);
+
+fn narrow(constant: i64) -> Result<i32, DbmError> {
+    if !(-MAX_CONSTANT..=MAX_CONSTANT).contains(&constant) {
+        return Err(DbmError::Overflow);
+    }
+    return Ok(constant as i32);
+}
+
+fn narrow_all(constants: &[i64]) -> Result<Vec<i32>, DbmError> {
+    return constants.iter().map(|&constant| narrow(constant)).collect();
+}
+
+fn narrow_bound(bound: Bound<i64>) -> Result<Bound<i32>, DbmError> {
+    return Ok(match bound {
+        Bound::LessThan(constant) => Bound::LessThan(narrow(constant)?),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(narrow(constant)?),
+        Bound::Infinity => Bound::Infinity,
+    });
+}
+
+fn narrow_constraints(constraints: &[Constraint<i64>]) -> Result<Vec<Constraint<i32>>, DbmError> {
+    return constraints
+        .iter()
+        .map(|constraint| Ok(Constraint::new(constraint.i, constraint.j, narrow_bound(constraint.bound)?)))
+        .collect();
+}
+
+fn widen_bound(bound: Bound<i32>) -> Bound<i64> {
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(constant.into()),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(constant.into()),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+// Every operation goes through the 32-bit adapter, so the native udbm fast paths are kept.
+impl DBM<i64> for UDBM_I64 {
+    fn try_init(dim: usize) -> Result<Self, DbmError> {
+        return Ok(UDBM_I64(DBM::<i32>::try_init(dim)?));
+    }
+
+    fn try_zero(dim: usize) -> Result<Self, DbmError> {
+        return Ok(UDBM_I64(DBM::<i32>::try_zero(dim)?));
+    }
+
+    fn dimension(dbm: &Self) -> usize {
+        return DBM::<i32>::dimension(&dbm.0);
+    }
+
+    fn is_empty(dbm: &Self) -> bool {
+        return DBM::<i32>::is_empty(&dbm.0);
+    }
+
+    fn max_constant() -> i64 {
+        return MAX_CONSTANT;
+    }
+
+    fn try_get_bound(dbm: &Self, i: usize, j: usize) -> Result<Bound<i64>, DbmError> {
+        return DBM::<i32>::try_get_bound(&dbm.0, i, j).map(widen_bound);
+    }
+
+    fn try_is_included_in(lhs: &Self, rhs: &Self) -> Result<bool, DbmError> {
+        return DBM::<i32>::try_is_included_in(&lhs.0, &rhs.0);
+    }
+
+    fn try_is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<i64>) -> Result<bool, DbmError> {
+        return DBM::<i32>::try_is_satisfied(&dbm.0, i, j, narrow_bound(bound)?);
+    }
+
+    fn try_close(dbm: &mut Self) -> Result<bool, DbmError> {
+        return DBM::<i32>::try_close(&mut dbm.0);
+    }
+
+    fn try_close_ij(dbm: &mut Self, i: usize, j: usize) -> Result<bool, DbmError> {
+        return DBM::<i32>::try_close_ij(&mut dbm.0, i, j);
+    }
+
+    fn try_close_clock(dbm: &mut Self, clock: usize) -> Result<bool, DbmError> {
+        return DBM::<i32>::try_close_clock(&mut dbm.0, clock);
+    }
+
+    fn try_future(dbm: &mut Self) -> Result<(), DbmError> {
+        return DBM::<i32>::try_future(&mut dbm.0);
+    }
+
+    fn try_past(dbm: &mut Self) -> Result<(), DbmError> {
+        return DBM::<i32>::try_past(&mut dbm.0);
+    }
+
+    fn try_restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<i64>) -> Result<(), DbmError> {
+        return DBM::<i32>::try_restrict(&mut dbm.0, i, j, narrow_bound(bound)?);
+    }
+
+    fn try_restrict_unchecked(dbm: &mut Self, i: usize, j: usize, bound: Bound<i64>) -> Result<(), DbmError> {
+        return DBM::<i32>::try_restrict_unchecked(&mut dbm.0, i, j, narrow_bound(bound)?);
+    }
+
+    fn try_free(dbm: &mut Self, clock: usize) -> Result<(), DbmError> {
+        return DBM::<i32>::try_free(&mut dbm.0, clock);
+    }
+
+    fn try_assign(dbm: &mut Self, clock: usize, constant: i64) -> Result<(), DbmError> {
+        return DBM::<i32>::try_assign(&mut dbm.0, clock, narrow(constant)?);
+    }
+
+    fn try_copy(dbm: &mut Self, clock_to: usize, clock_from: usize) -> Result<(), DbmError> {
+        return DBM::<i32>::try_copy(&mut dbm.0, clock_to, clock_from);
+    }
+
+    fn try_shift(dbm: &mut Self, clock: usize, shift_constant: i64) -> Result<(), DbmError> {
+        return DBM::<i32>::try_shift(&mut dbm.0, clock, narrow(shift_constant)?);
+    }
+
+    fn try_future_within(dbm: &mut Self, invariant: &[Constraint<i64>]) -> Result<(), DbmError> {
+        return DBM::<i32>::try_future_within(&mut dbm.0, &narrow_constraints(invariant)?);
+    }
+
+    fn try_past_within(dbm: &mut Self, guard: &[Constraint<i64>]) -> Result<(), DbmError> {
+        return DBM::<i32>::try_past_within(&mut dbm.0, &narrow_constraints(guard)?);
+    }
+
+    fn try_update(dbm: &mut Self, clock_to: usize, clock_from: usize, constant: i64) -> Result<(), DbmError> {
+        return DBM::<i32>::try_update(&mut dbm.0, clock_to, clock_from, narrow(constant)?);
+    }
+
+    fn try_intersect(dbm: &mut Self, other: &Self) -> Result<(), DbmError> {
+        return DBM::<i32>::try_intersect(&mut dbm.0, &other.0);
+    }
+
+    fn try_convex_hull(dbm: &mut Self, other: &Self) -> Result<(), DbmError> {
+        return DBM::<i32>::try_convex_hull(&mut dbm.0, &other.0);
+    }
+
+    fn try_relation(lhs: &Self, rhs: &Self) -> Result<Relation, DbmError> {
+        return DBM::<i32>::try_relation(&lhs.0, &rhs.0);
+    }
+
+    fn try_extrapolate_max_bounds(dbm: &mut Self, max_bounds: &[i64]) -> Result<(), DbmError> {
+        return DBM::<i32>::try_extrapolate_max_bounds(&mut dbm.0, &narrow_all(max_bounds)?);
+    }
+
+    fn try_extrapolate_diagonal_max_bounds(dbm: &mut Self, max_bounds: &[i64]) -> Result<(), DbmError> {
+        return DBM::<i32>::try_extrapolate_diagonal_max_bounds(&mut dbm.0, &narrow_all(max_bounds)?);
+    }
+
+    fn try_extrapolate_lu_bounds(dbm: &mut Self, lower: &[i64], upper: &[i64]) -> Result<(), DbmError> {
+        return DBM::<i32>::try_extrapolate_lu_bounds(&mut dbm.0, &narrow_all(lower)?, &narrow_all(upper)?);
+    }
+
+    fn try_extrapolate_diagonal_lu_bounds(dbm: &mut Self, lower: &[i64], upper: &[i64]) -> Result<(), DbmError> {
+        return DBM::<i32>::try_extrapolate_diagonal_lu_bounds(&mut dbm.0, &narrow_all(lower)?, &narrow_all(upper)?);
+    }
+}
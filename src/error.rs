@@ -0,0 +1,88 @@
+use crate::Constraint;
+use std::fmt;
+
+/// Errors reported by the fallible `try_*` operations of the `DBM` trait.
+/// Every backend maps its native errors onto these variants.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DbmError {
+    /// A clock index was not below the dimension of the DBM.
+    ClockOutOfRange { clock: usize, dim: usize },
+    /// Two DBMs of different dimensions were combined.
+    DimensionMismatch { lhs: usize, rhs: usize },
+    /// A constant, or a bound computed from it, exceeds the constant limit of the backend. See the
+    /// overflow policy on the `DBM` trait.
+    Overflow,
+    /// The operation needs a non-empty zone, but the DBM is empty.
+    InconsistentZone,
+    /// The backend cannot build a DBM of dimension `dim`, since it supports at most `max`.
+    DimensionTooLarge { dim: usize, max: usize },
+    /// The backend cannot perform the operation.
+    Unsupported(String),
+    /// A backend error with no more specific variant.
+    Backend(String),
+}
+
+impl fmt::Display for DbmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            DbmError::ClockOutOfRange { clock, dim } => {
+                write!(f, "clock {} is out of range for a DBM of dimension {}", clock, dim)
+            }
+            DbmError::DimensionMismatch { lhs, rhs } => {
+                write!(f, "DBMs have different dimensions ({} and {})", lhs, rhs)
+            }
+            DbmError::Overflow => write!(f, "bound exceeds the constant limit of the backend"),
+            DbmError::InconsistentZone => write!(f, "operation requires a non-empty zone"),
+            DbmError::DimensionTooLarge { dim, max } => {
+                write!(f, "dimension {} exceeds the largest dimension {} of the backend", dim, max)
+            }
+            DbmError::Unsupported(reason) => write!(f, "unsupported operation: {}", reason),
+            DbmError::Backend(reason) => write!(f, "backend error: {}", reason),
+        };
+    }
+}
+
+impl std::error::Error for DbmError {}
+
+pub(crate) fn check_clock(clock: usize, dim: usize) -> Result<(), DbmError> {
+    if clock >= dim {
+        return Err(DbmError::ClockOutOfRange { clock, dim });
+    }
+    return Ok(());
+}
+
+//every DBM has at least the reference clock 0
+pub(crate) fn check_dimension(dim: usize) -> Result<(), DbmError> {
+    return check_clock(0, dim);
+}
+
+pub(crate) fn check_dimensions(lhs: usize, rhs: usize) -> Result<(), DbmError> {
+    if lhs != rhs {
+        return Err(DbmError::DimensionMismatch { lhs, rhs });
+    }
+    return Ok(());
+}
+
+pub(crate) fn check_constraints<T>(constraints: &[Constraint<T>], dim: usize) -> Result<(), DbmError> {
+    for constraint in constraints {
+        check_clock(constraint.i, dim)?;
+        check_clock(constraint.j, dim)?;
+    }
+    return Ok(());
+}
+
+/// Maps a backend error onto the variant for its cause. rdbm reports errors without the clocks
+/// and dimension involved, so the cause is found again from the call: one of its `clocks` out of
+/// range, then an empty zone. Constants never reach a backend beyond its limit, so `Overflow` is
+/// reported by the adapters before the call. `Backend` is left for errors with no such cause.
+pub(crate) fn from_backend<E: fmt::Debug>(clocks: &[usize], dim: usize, empty: bool) -> impl FnOnce(E) -> DbmError + '_ {
+    return move |error| {
+        if let Some(&clock) = clocks.iter().find(|&&clock| clock >= dim) {
+            return DbmError::ClockOutOfRange { clock, dim };
+        }
+        if empty {
+            return DbmError::InconsistentZone;
+        }
+        return DbmError::Backend(format!("{:?}", error));
+    };
+}
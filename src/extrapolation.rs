@@ -0,0 +1,39 @@
+use crate::Bound;
+use std::ops::Neg;
+
+/// Applies Extra_LU (or Extra_LU+ when `diagonal` is set) from Behrmann et al., "Lower and upper
+/// bounds in zone-based abstractions of timed automata", to a closed matrix. Extra_M and Extra_M+
+/// are the special case `lower == upper`. Index 0 of `lower` and `upper` belongs to the reference
+/// clock and should be 0.
+///
+/// The result is generally not closed.
+pub(crate) fn extrapolate<T: Ord + Clone + Neg<Output = T>>(
+    matrix: &[Vec<Bound<T>>],
+    lower: &[T],
+    upper: &[T],
+    diagonal: bool,
+) -> Vec<Vec<Bound<T>>> {
+    let dim = matrix.len();
+    //D_0k < (<, -bound), i.e. the lower bound of clock k lies above `bound`. x_k > bound is not above it, while
+    //x_k >= bound + 1 is
+    let lower_bound_above = |k: usize, bound: &T| matrix[0][k] < Bound::LessThan(-bound.clone());
+    let mut extrapolated = matrix.to_vec();
+    for i in 0..dim {
+        for j in (0..dim).filter(|&j| j != i) {
+            let above_lower = matrix[i][j] > Bound::LessThanEqual(lower[i].clone());
+            extrapolated[i][j] = if above_lower || (diagonal && lower_bound_above(i, &lower[i])) {
+                Bound::Infinity
+            } else if diagonal && lower_bound_above(j, &upper[j]) {
+                match i {
+                    0 => Bound::LessThan(-upper[j].clone()),
+                    _ => Bound::Infinity,
+                }
+            } else if !diagonal && matrix[i][j] < Bound::LessThan(-upper[j].clone()) {
+                Bound::LessThan(-upper[j].clone())
+            } else {
+                matrix[i][j].clone()
+            };
+        }
+    }
+    return extrapolated;
+}
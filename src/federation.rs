@@ -0,0 +1,650 @@
+use crate::error::check_dimensions;
+use crate::{minimal_constraints, Constraint, DbmError, Relation, DBM};
+use num::Zero;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use std::ops::{Add, Neg};
+
+/// A union of zones of the same dimension, representing sets of clock valuations that need not be
+/// convex. Works over any `DBM` backend.
+///
+/// Empty zones are never stored. Unions only append zones, so a federation may hold zones that are
+/// included in others until `reduce` is called.
+#[derive(Clone, Debug)]
+pub struct Federation<T, D: DBM<T>> {
+    dim: usize,
+    zones: Vec<D>,
+    constant: PhantomData<T>,
+}
+
+impl<T: Ord, D: DBM<T> + Clone> Federation<T, D> {
+    /// The empty federation of dimension `dim`.
+    pub fn new(dim: usize) -> Self {
+        return Federation { dim, zones: Vec::new(), constant: PhantomData };
+    }
+
+    pub fn from_zone(zone: D) -> Self {
+        let mut federation = Federation::new(D::dimension(&zone));
+        federation.add_zone(zone);
+        return federation;
+    }
+
+    pub fn dimension(&self) -> usize {
+        return self.dim;
+    }
+
+    pub fn zones(&self) -> &[D] {
+        return &self.zones;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.zones.is_empty();
+    }
+
+    /// Adds `zone` to the union.
+    pub fn try_add_zone(&mut self, zone: D) -> Result<(), DbmError> {
+        check_dimensions(self.dim, D::dimension(&zone))?;
+        if !D::is_empty(&zone) {
+            self.zones.push(zone);
+        }
+        return Ok(());
+    }
+
+    pub fn add_zone(&mut self, zone: D) {
+        return self.try_add_zone(zone).unwrap();
+    }
+
+    pub fn try_union(&mut self, other: &Self) -> Result<(), DbmError> {
+        check_dimensions(self.dim, other.dim)?;
+        self.zones.extend(other.zones.iter().cloned());
+        return Ok(());
+    }
+
+    pub fn union(&mut self, other: &Self) {
+        return self.try_union(other).unwrap();
+    }
+
+    /// Intersects every zone with `zone`, dropping the ones that become empty.
+    pub fn try_intersect_zone(&mut self, zone: &D) -> Result<(), DbmError> {
+        check_dimensions(self.dim, D::dimension(zone))?;
+        for own in self.zones.iter_mut() {
+            D::try_intersect(own, zone)?;
+        }
+        self.zones.retain(|own| !D::is_empty(own));
+        return Ok(());
+    }
+
+    pub fn intersect_zone(&mut self, zone: &D) {
+        return self.try_intersect_zone(zone).unwrap();
+    }
+
+    /// Intersects the federation with `other`, which distributes into the pairwise intersections
+    /// of their zones.
+    pub fn try_intersect(&mut self, other: &Self) -> Result<(), DbmError> {
+        check_dimensions(self.dim, other.dim)?;
+        let mut intersections = Vec::with_capacity(self.zones.len() * other.zones.len());
+        for own in self.zones.iter() {
+            for zone in other.zones.iter() {
+                let mut intersection = own.clone();
+                D::try_intersect(&mut intersection, zone)?;
+                if !D::is_empty(&intersection) {
+                    intersections.push(intersection);
+                }
+            }
+        }
+        self.zones = intersections;
+        return Ok(());
+    }
+
+    pub fn intersect(&mut self, other: &Self) {
+        return self.try_intersect(other).unwrap();
+    }
+
+    /// Removes every zone that is included in another zone of the federation. Of several equal
+    /// zones, the first is kept.
+    pub fn reduce(&mut self) {
+        let mut kept: Vec<D> = Vec::with_capacity(self.zones.len());
+        for zone in self.zones.drain(..) {
+            let mut is_subsumed = false;
+            let mut index = 0;
+            while index < kept.len() && !is_subsumed {
+                match D::relation(&zone, &kept[index]) {
+                    Relation::Equal | Relation::Subset => is_subsumed = true,
+                    Relation::Superset => {
+                        kept.swap_remove(index);
+                        continue;
+                    }
+                    Relation::Different => {}
+                }
+                index += 1;
+            }
+            if !is_subsumed {
+                kept.push(zone);
+            }
+        }
+        self.zones = kept;
+    }
+}
+
+impl<T: Ord + Clone + Zero + Neg<Output = T> + Add<Output = T> + Into<i64> + TryFrom<i64>, D: DBM<T> + Clone> Federation<T, D> {
+    /// Removes the valuations of `zone` from the federation.
+    pub fn try_subtract_zone(&mut self, zone: &D) -> Result<(), DbmError> {
+        check_dimensions(self.dim, D::dimension(zone))?;
+        let mut difference = Federation::new(self.dim);
+        for own in self.zones.iter() {
+            difference.zones.extend(try_subtract(own, zone)?.zones);
+        }
+        *self = difference;
+        return Ok(());
+    }
+
+    pub fn subtract_zone(&mut self, zone: &D) {
+        return self.try_subtract_zone(zone).unwrap();
+    }
+
+    pub fn try_subtract(&mut self, other: &Self) -> Result<(), DbmError> {
+        check_dimensions(self.dim, other.dim)?;
+        for zone in other.zones.iter() {
+            self.try_subtract_zone(zone)?;
+        }
+        return Ok(());
+    }
+
+    pub fn subtract(&mut self, other: &Self) {
+        return self.try_subtract(other).unwrap();
+    }
+
+    /// Whether the union of the federation is included in the union of `other`. This also holds
+    /// when a zone is covered only by several zones of `other` together.
+    pub fn try_is_included_in(&self, other: &Self) -> Result<bool, DbmError> {
+        check_dimensions(self.dim, other.dim)?;
+        for own in self.zones.iter() {
+            //cheap check first, the subtraction is only needed for zones covered by several others
+            if other.zones.iter().any(|zone| D::is_included_in(own, zone)) {
+                continue;
+            }
+            let mut rest = Federation::from_zone(own.clone());
+            rest.try_subtract(other)?;
+            if !rest.is_empty() {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+
+    pub fn is_included_in(&self, other: &Self) -> bool {
+        return self.try_is_included_in(other).unwrap();
+    }
+}
+
+/// The zone subtraction `zone \ other`, as a federation of disjoint zones.
+///
+/// Every constraint of `minimal_constraints(other)` that cuts what is left of `zone` splits off
+/// the part violating it, and if the zones do not overlap `zone` is returned whole. The pieces
+/// are then merged into the fewest zones that are each a union of pieces, so a convex difference
+/// is always a single zone. Finding the fewest is exponential in the number of pieces in the worst
+/// case, though groups of pieces whose hull reaches into `other` are never searched further.
+pub fn try_subtract<T, D>(zone: &D, other: &D) -> Result<Federation<T, D>, DbmError>
+where
+    T: Ord + Clone + Zero + Neg<Output = T> + Add<Output = T> + Into<i64> + TryFrom<i64>,
+    D: DBM<T> + Clone,
+{
+    let dim = D::dimension(zone);
+    check_dimensions(dim, D::dimension(other))?;
+    let mut difference = Federation::new(dim);
+    for piece in merge(split(zone, other)?, other)? {
+        difference.try_add_zone(piece)?;
+    }
+    return Ok(difference);
+}
+
+//the disjoint non-empty pieces of `zone \ other`, one per constraint of `other` that cuts what
+//is left of `zone`
+fn split<T, D>(zone: &D, other: &D) -> Result<Vec<D>, DbmError>
+where
+    T: Ord + Clone + Zero + Neg<Output = T> + Add<Output = T> + Into<i64> + TryFrom<i64>,
+    D: DBM<T> + Clone,
+{
+    let mut remainder = zone.clone();
+    D::try_intersect(&mut remainder, other)?;
+    if D::is_empty(&remainder) {
+        return Ok(match D::is_empty(zone) {
+            true => Vec::new(),
+            false => vec![zone.clone()],
+        });
+    }
+    let mut pieces = Vec::new();
+    let mut remainder = zone.clone();
+    for Constraint { i, j, bound } in minimal_constraints(other) {
+        if D::try_get_bound(&remainder, i, j)? <= bound {
+            continue;
+        }
+        let mut outside = remainder.clone();
+        D::try_restrict(&mut outside, j, i, -bound.clone())?;
+        if !D::is_empty(&outside) {
+            pieces.push(outside);
+        }
+        D::try_restrict(&mut remainder, i, j, bound)?;
+    }
+    return Ok(pieces);
+}
+
+//merges the disjoint `pieces` of a difference with `other` into as few zones as possible, keeping
+//them disjoint. A group of pieces forms a zone when its hull lies in their union, that is when the
+//hull misses `other` and every piece outside the group, since the pieces and `other` cover `zone`
+fn merge<T, D>(pieces: Vec<D>, other: &D) -> Result<Vec<D>, DbmError>
+where
+    T: Ord + Clone + Zero + Neg<Output = T> + Add<Output = T> + Into<i64> + TryFrom<i64>,
+    D: DBM<T> + Clone,
+{
+    if pieces.len() < 2 {
+        return Ok(pieces);
+    }
+    //the hull of all pieces lies in the subtracted zone, so it is the difference exactly when
+    //it misses `other`
+    let mut hull = pieces[0].clone();
+    for piece in pieces.iter().skip(1) {
+        D::try_convex_hull(&mut hull, piece)?;
+    }
+    if !meets(&hull, other)? {
+        return Ok(vec![hull]);
+    }
+    let mut groups = Vec::new();
+    collect_groups(&pieces, other, &mut Vec::new(), None, &mut groups)?;
+    //every piece on its own is a group, so at the latest there is a partition with one zone per piece
+    let mut chosen = Vec::new();
+    for count in 2..=pieces.len() {
+        if partition(&groups, &mut vec![false; pieces.len()], count, &mut chosen) {
+            break;
+        }
+    }
+    return Ok(chosen.into_iter().map(|group| groups[group].1.clone()).collect());
+}
+
+//adds to `groups` every group that extends `members` by pieces after the last of them and forms
+//a zone, with its hull. Hulls only grow as pieces are added, so a group whose hull meets `other`
+//is not extended any further
+fn collect_groups<T, D>(pieces: &[D], other: &D, members: &mut Vec<usize>, hull: Option<&D>, groups: &mut Vec<(Vec<usize>, D)>) -> Result<(), DbmError>
+where
+    T: Ord + Clone + Zero + Neg<Output = T> + Add<Output = T> + Into<i64> + TryFrom<i64>,
+    D: DBM<T> + Clone,
+{
+    let first = members.last().map_or(0, |last| last + 1);
+    for next in first..pieces.len() {
+        let mut extended = pieces[next].clone();
+        if let Some(hull) = hull {
+            D::try_convex_hull(&mut extended, hull)?;
+        }
+        if meets(&extended, other)? {
+            continue;
+        }
+        members.push(next);
+        let mut is_zone = true;
+        for (_, piece) in pieces.iter().enumerate().filter(|(k, _)| !members.contains(k)) {
+            if meets(&extended, piece)? {
+                is_zone = false;
+                break;
+            }
+        }
+        if is_zone {
+            groups.push((members.clone(), extended.clone()));
+        }
+        collect_groups(pieces, other, members, Some(&extended), groups)?;
+        members.pop();
+    }
+    return Ok(());
+}
+
+//whether the pieces not yet `covered` can be split into at most `count` of the `groups`, which are
+//then added to `chosen`
+fn partition<D>(groups: &[(Vec<usize>, D)], covered: &mut [bool], count: usize, chosen: &mut Vec<usize>) -> bool {
+    let first = match covered.iter().position(|&covered| !covered) {
+        Some(first) => first,
+        None => return true,
+    };
+    if count == 0 {
+        return false;
+    }
+    for (group, (members, _)) in groups.iter().enumerate() {
+        if !members.contains(&first) || members.iter().any(|&member| covered[member]) {
+            continue;
+        }
+        members.iter().for_each(|&member| covered[member] = true);
+        chosen.push(group);
+        if partition(groups, covered, count - 1, chosen) {
+            return true;
+        }
+        chosen.pop();
+        members.iter().for_each(|&member| covered[member] = false);
+    }
+    return false;
+}
+
+fn meets<T, D: DBM<T> + Clone>(a: &D, b: &D) -> Result<bool, DbmError> {
+    let mut overlap = a.clone();
+    D::try_intersect(&mut overlap, b)?;
+    return Ok(!D::is_empty(&overlap));
+}
+
+pub fn subtract<T, D>(zone: &D, other: &D) -> Federation<T, D>
+where
+    T: Ord + Clone + Zero + Neg<Output = T> + Add<Output = T> + Into<i64> + TryFrom<i64>,
+    D: DBM<T> + Clone,
+{
+    return try_subtract(zone, other).unwrap();
+}
+
+#[cfg(test)]
+macro_rules! generate_federation_tests {
+    ($($name:ident: $type:ty, $constant:ty;)*) => {
+        $(
+            mod $name {
+                use super::*;
+
+                //the zone of valuations where `lower <= x_clock <= upper`
+                fn interval(dim: usize, clock: usize, lower: i8, upper: i8) -> $type {
+                    let mut zone:$type = DBM::init(dim);
+                    DBM::restrict(&mut zone, clock, 0, Bound::LessThanEqual(upper.into()));
+                    DBM::restrict(&mut zone, 0, clock, Bound::LessThanEqual((-lower).into()));
+                    return zone;
+                }
+
+                fn federation(zones: Vec<$type>) -> Federation<$constant, $type> {
+                    let mut federation = Federation::new(4);
+                    for zone in zones {
+                        federation.add_zone(zone);
+                    }
+                    return federation;
+                }
+
+                #[test]
+                fn test_new_is_empty() {
+                    let federation: Federation<$constant, $type> = Federation::new(4);
+                    assert_eq!(federation.is_empty(), true);
+                    assert_eq!(federation.dimension(), 4);
+                }
+
+                #[test]
+                fn test_empty_zone_is_dropped() {
+                    let mut zone = interval(4, 1, 0, 5);
+                    DBM::restrict(&mut zone, 0, 1, Bound::LessThanEqual(-10));
+                    let federation = federation(vec![zone]);
+                    assert_eq!(federation.is_empty(), true);
+                }
+
+                #[test]
+                fn test_union() {
+                    let a = federation(vec![interval(4, 1, 0, 2)]);
+                    let b = federation(vec![interval(4, 1, 5, 7)]);
+                    let mut union = a.clone();
+                    union.union(&b);
+                    assert_eq!(union.zones().len(), 2);
+                    assert_eq!(a.is_included_in(&union), true);
+                    assert_eq!(b.is_included_in(&union), true);
+                    assert_eq!(union.is_included_in(&a), false);
+                    assert_eq!(union.is_included_in(&b), false);
+                }
+
+                #[test]
+                fn test_intersect() {
+                    let mut federation = federation(vec![interval(4, 1, 0, 2), interval(4, 1, 5, 7)]);
+                    federation.intersect_zone(&interval(4, 1, 1, 6));
+                    let expected = self::federation(vec![interval(4, 1, 1, 2), interval(4, 1, 5, 6)]);
+                    assert_eq!(federation.zones().len(), 2);
+                    assert_eq!(federation.is_included_in(&expected), true);
+                    assert_eq!(expected.is_included_in(&federation), true);
+                }
+
+                #[test]
+                fn test_intersect_federations() {
+                    let mut a = federation(vec![interval(4, 1, 0, 2), interval(4, 1, 5, 7)]);
+                    let b = federation(vec![interval(4, 2, 0, 3), interval(4, 1, 6, 9)]);
+                    a.intersect(&b);
+                    a.reduce();
+                    let mut expected = federation(vec![interval(4, 1, 0, 2), interval(4, 1, 5, 7)]);
+                    expected.intersect_zone(&interval(4, 2, 0, 3));
+                    expected.add_zone(interval(4, 1, 6, 7));
+                    assert_eq!(a.is_included_in(&expected), true);
+                    assert_eq!(expected.is_included_in(&a), true);
+                }
+
+                #[test]
+                fn test_intersect_disjoint() {
+                    let mut a = federation(vec![interval(4, 1, 0, 2), interval(4, 1, 5, 7)]);
+                    let b = federation(vec![interval(4, 1, 3, 4)]);
+                    a.intersect(&b);
+                    assert_eq!(a.is_empty(), true);
+                }
+
+                #[test]
+                fn test_reduce() {
+                    let mut federation = federation(vec![
+                        interval(4, 1, 2, 3),
+                        interval(4, 1, 0, 5), //includes the first zone
+                        interval(4, 1, 0, 5), //equal to the second
+                        interval(4, 2, 0, 5),
+                    ]);
+                    let unreduced = federation.clone();
+                    federation.reduce();
+                    assert_eq!(federation.zones().len(), 2);
+                    assert_eq!(federation.is_included_in(&unreduced), true); //reduction never changes the represented set
+                    assert_eq!(unreduced.is_included_in(&federation), true);
+                }
+
+                //whether the valuation given by `doubled`, with every clock value doubled so that
+                //half-integer points can be sampled, lies in the zone
+                fn contains(zone: &$type, doubled: &[i64]) -> bool {
+                    return (0..doubled.len()).all(|i| {
+                        (0..doubled.len()).all(|j| {
+                            let difference = doubled[i] - doubled[j];
+                            match DBM::get_bound(zone, i, j) {
+                                Bound::LessThan(c) => difference < 2 * i64::from(c),
+                                Bound::LessThanEqual(c) => difference <= 2 * i64::from(c),
+                                Bound::Infinity => true,
+                            }
+                        })
+                    });
+                }
+
+                //samples x_1 and x_2 in steps of one half over [0, 10], with x_3 at 0 or 3
+                fn sample_points() -> Vec<Vec<i64>> {
+                    let mut points = Vec::new();
+                    for x1 in 0..=20 {
+                        for x2 in 0..=20 {
+                            for &x3 in [0, 6].iter() {
+                                points.push(vec![0, x1, x2, x3]);
+                            }
+                        }
+                    }
+                    return points;
+                }
+
+                //checks pointwise that `difference` is `zone \ other` and that its zones are disjoint
+                fn assert_difference(zone: &$type, other: &$type, difference: &Federation<$constant, $type>) {
+                    for point in sample_points() {
+                        let count = difference.zones().iter().filter(|z| contains(z, &point)).count();
+                        assert_eq!(count <= 1, true, "zones overlap at {:?}", point);
+                        let expected = contains(zone, &point) && !contains(other, &point);
+                        assert_eq!(count == 1, expected, "wrong membership of {:?}", point);
+                    }
+                }
+
+                //no two zones of the difference can be replaced by their hull
+                fn assert_pairwise_non_mergeable(difference: &Federation<$constant, $type>) {
+                    let zones = difference.zones();
+                    for a in 0..zones.len() {
+                        for b in a + 1..zones.len() {
+                            let mut hull = zones[a].clone();
+                            DBM::convex_hull(&mut hull, &zones[b]);
+                            let mut pair: Federation<$constant, $type> = Federation::from_zone(zones[a].clone());
+                            pair.add_zone(zones[b].clone());
+                            let hull: Federation<$constant, $type> = Federation::from_zone(hull);
+                            assert_eq!(hull.is_included_in(&pair), false, "zones {} and {} form a zone", a, b);
+                        }
+                    }
+                }
+
+                fn boxed(lower1: i8, upper1: i8, lower2: i8, upper2: i8) -> $type {
+                    let mut zone = interval(4, 1, lower1, upper1);
+                    DBM::intersect(&mut zone, &interval(4, 2, lower2, upper2));
+                    return zone;
+                }
+
+                #[test]
+                fn test_subtract_box() {
+                    //the frame around the box needs one zone per side, as the hull of any two
+                    //sides reaches into the box
+                    let zone = boxed(0, 10, 0, 10);
+                    let other = boxed(3, 6, 2, 8);
+                    let difference = subtract(&zone, &other);
+                    assert_eq!(difference.zones().len(), 4);
+                    assert_pairwise_non_mergeable(&difference);
+                    assert_difference(&zone, &other, &difference);
+                }
+
+                #[test]
+                fn test_subtract_diagonal() {
+                    let zone = boxed(0, 10, 0, 10);
+                    let mut other = zone.clone();
+                    DBM::restrict(&mut other, 1, 2, Bound::LessThanEqual(2));
+                    DBM::restrict(&mut other, 2, 1, Bound::LessThan(1));
+                    let difference = subtract(&zone, &other);
+                    assert_eq!(difference.zones().len(), 2);
+                    assert_difference(&zone, &other, &difference);
+                }
+
+                #[test]
+                fn test_subtract_merges_pieces() {
+                    //the cut at x_1 <= 6 lies within the cut at x_1 - x_2 <= -2, so the difference
+                    //is the single zone where x_1 - x_2 > -2
+                    let zone = boxed(0, 10, 0, 3);
+                    let mut other: $type = DBM::init(4);
+                    DBM::restrict(&mut other, 1, 0, Bound::LessThanEqual(6));
+                    DBM::restrict(&mut other, 1, 2, Bound::LessThanEqual(-2));
+                    let difference = subtract(&zone, &other);
+                    assert_eq!(difference.zones().len(), 1);
+                    let mut expected = zone.clone();
+                    DBM::restrict(&mut expected, 2, 1, Bound::LessThan(2));
+                    assert_eq!(DBM::relation(&difference.zones()[0], &expected), Relation::Equal);
+                    assert_difference(&zone, &other, &difference);
+                }
+
+                #[test]
+                fn test_subtract_fewest_zones() {
+                    //the cuts leave x_1 < 3, then x_2 < 3 and x_1 - x_2 > 0 to the right of it. The
+                    //last two form the zone x_2 < x_1 together, and no single zone is possible, as
+                    //the difference holds (0, 10) and (10, 9) but not (5, 9.5) between them
+                    let zone = boxed(0, 10, 0, 10);
+                    let mut other: $type = DBM::init(4);
+                    DBM::restrict(&mut other, 0, 1, Bound::LessThanEqual(-3));
+                    DBM::restrict(&mut other, 0, 2, Bound::LessThanEqual(-3));
+                    DBM::restrict(&mut other, 1, 2, Bound::LessThanEqual(0));
+                    let difference = subtract(&zone, &other);
+                    assert_eq!(difference.zones().len(), 2);
+                    let mut left = zone.clone();
+                    DBM::restrict(&mut left, 1, 0, Bound::LessThan(3));
+                    let mut below = zone.clone();
+                    DBM::restrict(&mut below, 0, 1, Bound::LessThanEqual(-3));
+                    DBM::restrict(&mut below, 2, 1, Bound::LessThan(0));
+                    for expected in [left, below].iter() {
+                        assert_eq!(difference.zones().iter().any(|z| DBM::relation(z, expected) == Relation::Equal), true);
+                    }
+                    assert_difference(&zone, &other, &difference);
+                }
+
+                #[test]
+                fn test_subtract_pairwise_non_mergeable() {
+                    //a gap leaves one zone on each side, and the hull of the two reaches into it
+                    let zone = interval(4, 1, 0, 10);
+                    let other = interval(4, 1, 3, 5);
+                    let difference = subtract(&zone, &other);
+                    assert_eq!(difference.zones().len(), 2);
+                    assert_pairwise_non_mergeable(&difference);
+                    assert_difference(&zone, &other, &difference);
+                }
+
+                #[test]
+                fn test_subtract_strict_bound() {
+                    let zone = interval(4, 1, 0, 10);
+                    let mut other: $type = DBM::init(4);
+                    DBM::restrict(&mut other, 1, 0, Bound::LessThan(5));
+                    let difference = subtract(&zone, &other);
+                    assert_eq!(difference.zones().len(), 1);
+                    assert_eq!(DBM::get_bound(&difference.zones()[0], 0, 1), Bound::LessThanEqual(-5));
+                    assert_difference(&zone, &other, &difference);
+                }
+
+                #[test]
+                fn test_subtract_including_zone() {
+                    let zone = boxed(2, 4, 2, 4);
+                    let difference = subtract(&zone, &boxed(0, 10, 0, 10));
+                    assert_eq!(difference.is_empty(), true);
+                    assert_eq!(subtract(&zone, &zone).is_empty(), true);
+                }
+
+                #[test]
+                fn test_subtract_disjoint_zone() {
+                    let zone = boxed(0, 2, 0, 2);
+                    let other = boxed(5, 7, 0, 2);
+                    let difference = subtract(&zone, &other);
+                    assert_eq!(difference.zones().len(), 1);
+                    assert_eq!(DBM::relation(&difference.zones()[0], &zone), Relation::Equal);
+                }
+
+                #[test]
+                fn test_subtract_from_empty_zone() {
+                    let mut zone = interval(4, 1, 0, 5);
+                    DBM::restrict(&mut zone, 0, 1, Bound::LessThanEqual(-10));
+                    assert_eq!(subtract(&zone, &boxed(0, 2, 0, 2)).is_empty(), true);
+                }
+
+                #[test]
+                fn test_subtract_federation() {
+                    let mut federation = federation(vec![interval(4, 1, 0, 10)]);
+                    federation.subtract(&self::federation(vec![interval(4, 1, 2, 3), interval(4, 1, 5, 6)]));
+                    assert_eq!(federation.zones().len(), 3);
+                    let expected = self::federation(vec![interval(4, 1, 0, 10)]);
+                    assert_eq!(federation.is_included_in(&expected), true);
+                    assert_eq!(expected.is_included_in(&federation), false);
+                }
+
+                #[test]
+                fn test_inclusion_in_covering_zones() {
+                    //neither zone of the cover includes the interval, but their union does
+                    let zone = federation(vec![interval(4, 1, 0, 5)]);
+                    let cover = federation(vec![interval(4, 1, 0, 3), interval(4, 1, 2, 5)]);
+                    assert_eq!(zone.is_included_in(&cover), true);
+                    let gap = federation(vec![interval(4, 1, 0, 2), interval(4, 1, 3, 5)]);
+                    assert_eq!(zone.is_included_in(&gap), false);
+                }
+
+                #[test]
+                fn test_try_subtract_dimension_mismatch() {
+                    let zone: $type = DBM::init(4);
+                    let other: $type = DBM::init(5);
+                    assert_eq!(try_subtract(&zone, &other).err(), Some(DbmError::DimensionMismatch { lhs: 4, rhs: 5 }));
+                }
+
+                #[test]
+                fn test_try_union_dimension_mismatch() {
+                    let mut a: Federation<$constant, $type> = Federation::new(4);
+                    let b: Federation<$constant, $type> = Federation::new(5);
+                    assert_eq!(a.try_union(&b), Err(DbmError::DimensionMismatch { lhs: 4, rhs: 5 }));
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{subtract, try_subtract, Bound, DbmError, Federation, Relation, DBM, PARDIBAAL, RDBM, RDBM_BITVEC, RDBM_V1, UDBM, REFERENCE};
+    generate_federation_tests! {
+        udbm: UDBM, i32;
+        rdbm: RDBM<i8>, i8;
+        rdbm_v1: RDBM_V1<i8>, i8;
+        rdbm_bitvec: RDBM_BITVEC<i8>, i8;
+        pardibaal: PARDIBAAL, i32;
+        reference: REFERENCE, i64;
+    }
+}
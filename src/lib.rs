@@ -1,29 +1,624 @@
+mod bound;
+mod constraint;
+mod error;
+mod extrapolation;
+mod federation;
+mod overflow;
+mod point;
+mod relation;
 mod dbms {
     pub mod rdbm;
     pub mod rdbm_v1;
     pub mod rdbm_bitvec;
     pub mod udbm;
+    pub mod udbm_i64;
+    pub mod pardibaal;
+    pub mod reference;
 }
+pub use bound::Bound;
+pub use constraint::{minimal_constraints, Constraint};
+pub use error::DbmError;
+pub use federation::{subtract, try_subtract, Federation};
+pub use point::Rational;
+pub use relation::Relation;
 pub use dbms::rdbm::RDBM;
 pub use dbms::rdbm_v1::RDBM_V1;
 pub use dbms::rdbm_bitvec::RDBM_BITVEC;
+pub use dbms::pardibaal::PARDIBAAL;
 pub use dbms::udbm::UDBM; //had some trouble with namespacing in the original repo, and decided to just leave it. Might fix later (probably not)
+pub use dbms::udbm_i64::UDBM_I64;
+pub use dbms::reference::REFERENCE;
 
-pub trait DBM<T> {
-    fn init(dim: usize) -> Self;
-    fn zero(dim: usize) -> Self;
-    fn is_included_in(rhs_dbm: &Self, lhs_dbm: &Self) -> bool;
-    fn is_satisfied(dbm: &Self, i: usize, j: usize, bound_is_strict: bool, constant: T) -> bool;
-
-    fn close(dbm: &mut Self);
-
-    fn future(dbm: &mut Self);
-    fn past(dbm: &mut Self);
-    fn restrict(dbm: &mut Self, i: usize, j: usize, bound_is_strict: bool, constant: T);
-    fn free(dbm: &mut Self, clock: usize);
-    fn assign(dbm: &mut Self, clock: usize, constant: T);
-    fn copy(dbm: &mut Self, clock_to: usize, clock_from: usize);
-    fn shift(dbm: &mut Self, clock: usize, shift_constant: T);
+use num::Zero;
+use std::convert::TryFrom;
+use std::ops::{Add, Neg};
+
+/// Operations shared by every DBM backend.
+///
+/// Backends implement the fallible `try_*` methods, which validate clock indices and map the
+/// backend's own errors onto `DbmError`. The infallible methods are thin wrappers that panic on
+/// such errors.
+///
+/// # Overflow
+///
+/// Every backend accepts constants up to a fixed limit in absolute value, chosen so that its
+/// closure never overflows its constant type. The limits differ per backend, so a constant that
+/// one backend accepts may overflow in another:
+///
+/// - rdbm and pardibaal: a third of the range of `T`, `(T::MAX - 1) / 3`, which is 42 for `i8`
+///   and 715,827,882 for `i32`.
+/// - udbm: a third of its raw 32-bit encoding, which is 357,913,940.
+/// - `REFERENCE`: `(i64::MAX - 1) / 3`, the limit rdbm would have for `i64`.
+/// - `UDBM_I64`: the limit of udbm, which it passes its constants to.
+///
+/// `max_constant` returns the limit of a backend.
+///
+/// An operation that is passed a constant beyond the limit, or whose result would have a bound
+/// beyond it, fails with `DbmError::Overflow`. Bounds are never saturated to infinity, since that
+/// would silently enlarge the zone. Restrictions that empty the zone do not overflow, and neither
+/// do operations that only loosen bounds, such as `future`, `free` and extrapolation.
+///
+/// The single-step operations `try_restrict`, `try_assign` and `try_shift` check before they
+/// change anything, so they leave the DBM unchanged on overflow. Operations built from several
+/// steps or constraints, such as `try_update`, `try_reset_many`, `try_intersect`,
+/// `try_future_within` and `try_past_within`, may be stopped partway or checked only once they
+/// are done, so the DBM is unspecified after they fail. Backends that take the steps one at a
+/// time may also fail when only an intermediate zone, not the result, has a bound beyond the
+/// limit.
+pub trait DBM<T>: Sized {
+    fn try_init(dim: usize) -> Result<Self, DbmError>;
+    fn try_zero(dim: usize) -> Result<Self, DbmError>;
+    fn dimension(dbm: &Self) -> usize;
+    fn is_empty(dbm: &Self) -> bool;
+    /// The largest constant the backend accepts, in absolute value.
+    fn max_constant() -> i64;
+    fn try_get_bound(dbm: &Self, i: usize, j: usize) -> Result<Bound<T>, DbmError>;
+    fn try_is_included_in(rhs_dbm: &Self, lhs_dbm: &Self) -> Result<bool, DbmError>;
+    /// Whether some valuation in the zone satisfies `x_i - x_j` within `bound`.
+    fn try_is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<T>) -> Result<bool, DbmError>;
+
+    /// Closes the DBM and reports whether its zone is non-empty.
+    fn try_close(dbm: &mut Self) -> Result<bool, DbmError>;
+
+    fn try_future(dbm: &mut Self) -> Result<(), DbmError>;
+    fn try_past(dbm: &mut Self) -> Result<(), DbmError>;
+    /// Restricts `x_i - x_j` to `bound`. Restricting to infinity leaves the zone unchanged.
+    fn try_restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<T>) -> Result<(), DbmError>;
+    /// Restricts like `try_restrict`, without checking that the bounds of the zone stay within
+    /// `max_constant`. For operations that check the zone they build once, instead of every step.
+    fn try_restrict_unchecked(dbm: &mut Self, i: usize, j: usize, bound: Bound<T>) -> Result<(), DbmError>;
+    fn try_free(dbm: &mut Self, clock: usize) -> Result<(), DbmError>;
+    fn try_assign(dbm: &mut Self, clock: usize, constant: T) -> Result<(), DbmError>;
+    fn try_copy(dbm: &mut Self, clock_to: usize, clock_from: usize) -> Result<(), DbmError>;
+    fn try_shift(dbm: &mut Self, clock: usize, shift_constant: T) -> Result<(), DbmError>;
+
+    fn init(dim: usize) -> Self {
+        return Self::try_init(dim).unwrap();
+    }
+
+    fn zero(dim: usize) -> Self {
+        return Self::try_zero(dim).unwrap();
+    }
+
+    fn get_bound(dbm: &Self, i: usize, j: usize) -> Bound<T> {
+        return Self::try_get_bound(dbm, i, j).unwrap();
+    }
+
+    /// Every bound of the DBM, indexed as `matrix[i][j]` for the bound on `x_i - x_j`.
+    fn to_matrix(dbm: &Self) -> Vec<Vec<Bound<T>>> {
+        let dim = Self::dimension(dbm);
+        return (0..dim)
+            .map(|i| (0..dim).map(|j| Self::get_bound(dbm, i, j)).collect())
+            .collect();
+    }
+
+    /// Builds the zone described by `matrix`, indexed like the result of `to_matrix`. Bounds
+    /// above `<= 0` on `x_0 - x_j` are ignored, since clocks are never negative.
+    ///
+    /// The matrix is closed and checked against `max_constant` once, before any bound is
+    /// restricted. Zones built one bound at a time pass through intermediate zones that may have
+    /// bounds beyond the limit, so only the zone described by the matrix has to be within it.
+    fn try_from_matrix(matrix: Vec<Vec<Bound<T>>>) -> Result<Self, DbmError>
+    where
+        T: Clone + Into<i64> + TryFrom<i64>,
+    {
+        let dim = matrix.len();
+        let mut dbm = Self::try_init(dim)?;
+        for row in &matrix {
+            error::check_dimensions(dim, row.len())?;
+        }
+        //the closed bounds are written, as every intermediate zone then contains the result and no bound is lost to an
+        //overflow on the way. An empty zone has no closed bounds, so its own bounds are written, which empties it
+        let closed = overflow::close_matrix(&matrix, Self::max_constant())?;
+        for (i, row) in closed.unwrap_or(matrix).into_iter().enumerate() {
+            for (j, bound) in row.into_iter().enumerate().filter(|&(j, _)| j != i) {
+                Self::try_restrict_unchecked(&mut dbm, i, j, bound)?;
+            }
+        }
+        return Ok(dbm);
+    }
+
+    fn from_matrix(matrix: Vec<Vec<Bound<T>>>) -> Self
+    where
+        T: Clone + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_from_matrix(matrix).unwrap();
+    }
+
+    fn is_included_in(rhs_dbm: &Self, lhs_dbm: &Self) -> bool {
+        return Self::try_is_included_in(rhs_dbm, lhs_dbm).unwrap();
+    }
+
+    fn is_satisfied(dbm: &Self, i: usize, j: usize, bound: Bound<T>) -> bool {
+        return Self::try_is_satisfied(dbm, i, j, bound).unwrap();
+    }
+
+    fn close(dbm: &mut Self) -> bool {
+        return Self::try_close(dbm).unwrap();
+    }
+
+    /// Restores closure when only the bound on `x_i - x_j` has been tightened since the DBM was
+    /// last closed, and reports whether the zone is non-empty. The default relaxes every bound
+    /// through `x_i - x_j` once, reading O(n^2) bounds and restricting only those that tighten;
+    /// udbm overrides it with its native incremental closure.
+    fn try_close_ij(dbm: &mut Self, i: usize, j: usize) -> Result<bool, DbmError>
+    where
+        T: Ord + Clone + Add<Output = T>,
+    {
+        let dim = Self::dimension(dbm);
+        error::check_clock(i, dim)?;
+        error::check_clock(j, dim)?;
+        let bound = Self::try_get_bound(dbm, i, j)?;
+        if bound.is_infinity() || Self::is_empty(dbm) {
+            return Ok(!Self::is_empty(dbm));
+        }
+        let mut to_j = Vec::with_capacity(dim);
+        let mut from_j = Vec::with_capacity(dim);
+        for k in 0..dim {
+            to_j.push(Self::try_get_bound(dbm, k, i)? + bound.clone());
+            from_j.push(Self::try_get_bound(dbm, j, k)?);
+        }
+        return relax_through(dbm, &to_j, &from_j);
+    }
+
+    fn close_ij(dbm: &mut Self, i: usize, j: usize) -> bool
+    where
+        T: Ord + Clone + Add<Output = T>,
+    {
+        return Self::try_close_ij(dbm, i, j).unwrap();
+    }
+
+    /// Like `try_close_ij`, for when only bounds involving `clock` have been tightened. The
+    /// default relaxes every bound through `clock` once.
+    fn try_close_clock(dbm: &mut Self, clock: usize) -> Result<bool, DbmError>
+    where
+        T: Ord + Clone + Add<Output = T>,
+    {
+        let dim = Self::dimension(dbm);
+        error::check_clock(clock, dim)?;
+        if Self::is_empty(dbm) {
+            return Ok(false);
+        }
+        let mut to_clock = Vec::with_capacity(dim);
+        let mut from_clock = Vec::with_capacity(dim);
+        for k in 0..dim {
+            to_clock.push(Self::try_get_bound(dbm, k, clock)?);
+            from_clock.push(Self::try_get_bound(dbm, clock, k)?);
+        }
+        return relax_through(dbm, &to_clock, &from_clock);
+    }
+
+    fn close_clock(dbm: &mut Self, clock: usize) -> bool
+    where
+        T: Ord + Clone + Add<Output = T>,
+    {
+        return Self::try_close_clock(dbm, clock).unwrap();
+    }
+
+    fn future(dbm: &mut Self) {
+        return Self::try_future(dbm).unwrap();
+    }
+
+    fn past(dbm: &mut Self) {
+        return Self::try_past(dbm).unwrap();
+    }
+
+    fn restrict(dbm: &mut Self, i: usize, j: usize, bound: Bound<T>) {
+        return Self::try_restrict(dbm, i, j, bound).unwrap();
+    }
+
+    fn free(dbm: &mut Self, clock: usize) {
+        return Self::try_free(dbm, clock).unwrap();
+    }
+
+    fn assign(dbm: &mut Self, clock: usize, constant: T) {
+        return Self::try_assign(dbm, clock, constant).unwrap();
+    }
+
+    fn copy(dbm: &mut Self, clock_to: usize, clock_from: usize) {
+        return Self::try_copy(dbm, clock_to, clock_from).unwrap();
+    }
+
+    fn shift(dbm: &mut Self, clock: usize, shift_constant: T) {
+        return Self::try_shift(dbm, clock, shift_constant).unwrap();
+    }
+
+    /// The general update `x_clock_to := x_clock_from + constant`. The default copies and then
+    /// shifts; backends with a native update override it.
+    fn try_update(dbm: &mut Self, clock_to: usize, clock_from: usize, constant: T) -> Result<(), DbmError> {
+        if clock_to != clock_from {
+            Self::try_copy(dbm, clock_to, clock_from)?;
+        }
+        return Self::try_shift(dbm, clock_to, constant);
+    }
+
+    fn update(dbm: &mut Self, clock_to: usize, clock_from: usize, constant: T) {
+        return Self::try_update(dbm, clock_to, clock_from, constant).unwrap();
+    }
+
+    /// Assigns every `(clock, value)` pair, as on an edge that resets several clocks. All clocks
+    /// are checked before any of them is assigned.
+    fn try_reset_many(dbm: &mut Self, resets: &[(usize, T)]) -> Result<(), DbmError>
+    where
+        T: Clone,
+    {
+        for (clock, _) in resets {
+            error::check_clock(*clock, Self::dimension(dbm))?;
+        }
+        for (clock, value) in resets {
+            Self::try_assign(dbm, *clock, value.clone())?;
+        }
+        return Ok(());
+    }
+
+    fn reset_many(dbm: &mut Self, resets: &[(usize, T)])
+    where
+        T: Clone,
+    {
+        return Self::try_reset_many(dbm, resets).unwrap();
+    }
+
+    /// Delays under an invariant: lets time pass and then restricts the zone by `invariant`, as in
+    /// the symbolic successor of a location. All clocks are checked before the zone is changed.
+    fn try_future_within(dbm: &mut Self, invariant: &[Constraint<T>]) -> Result<(), DbmError>
+    where
+        T: Clone,
+    {
+        error::check_constraints(invariant, Self::dimension(dbm))?;
+        Self::try_future(dbm)?;
+        return restrict_all(dbm, invariant);
+    }
+
+    fn future_within(dbm: &mut Self, invariant: &[Constraint<T>])
+    where
+        T: Clone,
+    {
+        return Self::try_future_within(dbm, invariant).unwrap();
+    }
+
+    /// The timed predecessor restricted by `guard`: computes the past of the zone and then
+    /// restricts it by `guard`. All clocks are checked before the zone is changed.
+    fn try_past_within(dbm: &mut Self, guard: &[Constraint<T>]) -> Result<(), DbmError>
+    where
+        T: Clone,
+    {
+        error::check_constraints(guard, Self::dimension(dbm))?;
+        Self::try_past(dbm)?;
+        return restrict_all(dbm, guard);
+    }
+
+    fn past_within(dbm: &mut Self, guard: &[Constraint<T>])
+    where
+        T: Clone,
+    {
+        return Self::try_past_within(dbm, guard).unwrap();
+    }
+
+    /// Conjoins `other` onto `dbm`. The default restricts `dbm` by every finite bound of `other`;
+    /// backends with a native intersection override it.
+    fn try_intersect(dbm: &mut Self, other: &Self) -> Result<(), DbmError> {
+        let dim = Self::dimension(dbm);
+        error::check_dimensions(dim, Self::dimension(other))?;
+        for i in 0..dim {
+            for j in (0..dim).filter(|&j| j != i) {
+                Self::try_restrict(dbm, i, j, Self::try_get_bound(other, i, j)?)?;
+            }
+        }
+        return Ok(());
+    }
+
+    fn intersect(dbm: &mut Self, other: &Self) {
+        return Self::try_intersect(dbm, other).unwrap();
+    }
+
+    /// Whether the valuation `point` lies in the zone, where `point[i]` is the value of clock `i`.
+    /// Clock values are taken relative to `point[0]`, the reference clock, which is normally 0.
+    fn try_contains_point(dbm: &Self, point: &[Rational]) -> Result<bool, DbmError>
+    where
+        T: Clone + Into<i64>,
+    {
+        error::check_dimensions(Self::dimension(dbm), point.len())?;
+        return Ok(point::contains_point(&Self::to_matrix(dbm), point));
+    }
+
+    fn contains_point(dbm: &Self, point: &[Rational]) -> bool
+    where
+        T: Clone + Into<i64>,
+    {
+        return Self::try_contains_point(dbm, point).unwrap();
+    }
+
+    /// A valuation in the zone, indexed like the argument of `contains_point` with 0 for the
+    /// reference clock, or `None` if the zone is empty.
+    fn sample_point(dbm: &Self) -> Option<Vec<Rational>>
+    where
+        T: Clone + Into<i64>,
+    {
+        return match Self::is_empty(dbm) {
+            true => None,
+            false => Some(point::sample_point(&Self::to_matrix(dbm))),
+        };
+    }
+
+    /// Replaces `dbm` by the smallest zone containing both `dbm` and `other`, taking the weaker
+    /// of the two bounds for every pair of clocks. The default rebuilds the zone from the merged
+    /// matrix; backends with a native convex union override it.
+    fn try_convex_hull(dbm: &mut Self, other: &Self) -> Result<(), DbmError>
+    where
+        T: Ord + Clone + Into<i64> + TryFrom<i64>,
+    {
+        let dim = Self::dimension(dbm);
+        error::check_dimensions(dim, Self::dimension(other))?;
+        if Self::is_empty(other) {
+            return Ok(());
+        }
+        let dbm_is_empty = Self::is_empty(dbm);
+        let mut matrix = Vec::with_capacity(dim);
+        for i in 0..dim {
+            let mut row = Vec::with_capacity(dim);
+            for j in 0..dim {
+                let bound = Self::try_get_bound(other, i, j)?;
+                row.push(match dbm_is_empty {
+                    true => bound,
+                    false => std::cmp::max(Self::try_get_bound(dbm, i, j)?, bound),
+                });
+            }
+            matrix.push(row);
+        }
+        *dbm = Self::try_from_matrix(matrix)?;
+        return Ok(());
+    }
+
+    fn convex_hull(dbm: &mut Self, other: &Self)
+    where
+        T: Ord + Clone + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_convex_hull(dbm, other).unwrap();
+    }
+
+    /// Classifies `lhs` against `rhs` in a single pass over the bounds, instead of calling
+    /// `is_included_in` in both directions. Like `is_included_in`, it counts an empty zone as
+    /// included in every zone.
+    fn try_relation(lhs: &Self, rhs: &Self) -> Result<Relation, DbmError>
+    where
+        T: Ord,
+    {
+        let dim = Self::dimension(lhs);
+        error::check_dimensions(dim, Self::dimension(rhs))?;
+        if let Some(relation) = Relation::of_empty(Self::is_empty(lhs), Self::is_empty(rhs)) {
+            return Ok(relation);
+        }
+        let mut is_subset = true;
+        let mut is_superset = true;
+        for i in 0..dim {
+            for j in 0..dim {
+                match Self::try_get_bound(lhs, i, j)?.cmp(&Self::try_get_bound(rhs, i, j)?) {
+                    std::cmp::Ordering::Less => is_superset = false,
+                    std::cmp::Ordering::Greater => is_subset = false,
+                    std::cmp::Ordering::Equal => {}
+                }
+                if !is_subset && !is_superset {
+                    return Ok(Relation::Different);
+                }
+            }
+        }
+        return Ok(Relation::from_inclusions(is_subset, is_superset));
+    }
+
+    fn relation(lhs: &Self, rhs: &Self) -> Relation
+    where
+        T: Ord,
+    {
+        return Self::try_relation(lhs, rhs).unwrap();
+    }
+
+    /// Extra_M: bounds above the maximal constant `max_bounds[i]` of their clock are dropped, and
+    /// lower bounds below it are widened to `-max_bounds[j]`. `max_bounds[0]` should be 0.
+    fn try_extrapolate_max_bounds(dbm: &mut Self, max_bounds: &[T]) -> Result<(), DbmError>
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return extrapolate_generic(dbm, max_bounds, max_bounds, false);
+    }
+
+    fn extrapolate_max_bounds(dbm: &mut Self, max_bounds: &[T])
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_extrapolate_max_bounds(dbm, max_bounds).unwrap();
+    }
+
+    /// Extra_M+: like Extra_M, but also drops the bounds of every clock whose lower bound lies
+    /// above its maximal constant.
+    fn try_extrapolate_diagonal_max_bounds(dbm: &mut Self, max_bounds: &[T]) -> Result<(), DbmError>
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return extrapolate_generic(dbm, max_bounds, max_bounds, true);
+    }
+
+    fn extrapolate_diagonal_max_bounds(dbm: &mut Self, max_bounds: &[T])
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_extrapolate_diagonal_max_bounds(dbm, max_bounds).unwrap();
+    }
+
+    /// Extra_LU: Extra_M with separate maximal constants for the lower bound guards (`lower`)
+    /// and upper bound guards (`upper`) of each clock.
+    fn try_extrapolate_lu_bounds(dbm: &mut Self, lower: &[T], upper: &[T]) -> Result<(), DbmError>
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return extrapolate_generic(dbm, lower, upper, false);
+    }
+
+    fn extrapolate_lu_bounds(dbm: &mut Self, lower: &[T], upper: &[T])
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_extrapolate_lu_bounds(dbm, lower, upper).unwrap();
+    }
+
+    /// Extra_LU+, the diagonal variant of Extra_LU.
+    fn try_extrapolate_diagonal_lu_bounds(dbm: &mut Self, lower: &[T], upper: &[T]) -> Result<(), DbmError>
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return extrapolate_generic(dbm, lower, upper, true);
+    }
+
+    fn extrapolate_diagonal_lu_bounds(dbm: &mut Self, lower: &[T], upper: &[T])
+    where
+        T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_extrapolate_diagonal_lu_bounds(dbm, lower, upper).unwrap();
+    }
+
+    /// Changes the clock set of `dbm` in the spirit of udbm's `dbm_shrinkExpand`. The new DBM has
+    /// dimension `mapping.len()`, and new clock `i` takes over the constraints of old clock
+    /// `mapping[i]`, or is unconstrained if it is `None`. The reference clock must map to itself.
+    ///
+    /// Resizing never adds valuations, so an empty zone stays empty. A DBM of the reference clock
+    /// alone cannot be empty, so resizing an empty zone to dimension 1 fails with
+    /// `DbmError::InconsistentZone`.
+    fn try_resize(dbm: &mut Self, mapping: &[Option<usize>]) -> Result<(), DbmError>
+    where
+        T: Clone + Zero + Into<i64> + TryFrom<i64>,
+    {
+        let dim = Self::dimension(dbm);
+        if mapping.first() != Some(&Some(0)) {
+            return Err(DbmError::Unsupported("the reference clock must map to itself".to_string()));
+        }
+        for &clock in mapping.iter().flatten() {
+            error::check_clock(clock, dim)?;
+        }
+        let is_empty = Self::is_empty(dbm);
+        if is_empty && mapping.len() == 1 {
+            return Err(DbmError::InconsistentZone);
+        }
+        let matrix = Self::to_matrix(dbm);
+        let resized = mapping
+            .iter()
+            .map(|i| {
+                mapping
+                    .iter()
+                    .map(|j| match (i, j) {
+                        (Some(i), Some(j)) => matrix[*i][*j].clone(),
+                        _ => Bound::Infinity,
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut resized = Self::try_from_matrix(resized)?;
+        if is_empty {
+            //the bounds of the retained clocks need not witness the emptiness, so force it with x_1 < 0
+            Self::try_restrict(&mut resized, 1, 0, Bound::LessThan(T::zero()))?;
+        }
+        *dbm = resized;
+        return Ok(());
+    }
+
+    fn resize(dbm: &mut Self, mapping: &[Option<usize>])
+    where
+        T: Clone + Zero + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_resize(dbm, mapping).unwrap();
+    }
+
+    /// Appends a new, unconstrained clock and returns its index.
+    fn add_clock(dbm: &mut Self) -> usize
+    where
+        T: Clone + Zero + Into<i64> + TryFrom<i64>,
+    {
+        let dim = Self::dimension(dbm);
+        let mapping: Vec<_> = (0..dim).map(Some).chain(std::iter::once(None)).collect();
+        Self::resize(dbm, &mapping);
+        return dim;
+    }
+
+    /// Removes `clock`, renumbering the clocks after it down by one.
+    fn try_remove_clock(dbm: &mut Self, clock: usize) -> Result<(), DbmError>
+    where
+        T: Clone + Zero + Into<i64> + TryFrom<i64>,
+    {
+        error::check_clock(clock, Self::dimension(dbm))?;
+        let mapping: Vec<_> = (0..Self::dimension(dbm)).filter(|&c| c != clock).map(Some).collect();
+        return Self::try_resize(dbm, &mapping);
+    }
+
+    fn remove_clock(dbm: &mut Self, clock: usize)
+    where
+        T: Clone + Zero + Into<i64> + TryFrom<i64>,
+    {
+        return Self::try_remove_clock(dbm, clock).unwrap();
+    }
+}
+
+fn restrict_all<T: Clone, D: DBM<T>>(dbm: &mut D, constraints: &[Constraint<T>]) -> Result<(), DbmError> {
+    for constraint in constraints {
+        D::try_restrict(dbm, constraint.i, constraint.j, constraint.bound.clone())?;
+    }
+    return Ok(());
+}
+
+//restricts every bound on x_k - x_l to the path `to[k] + from[l]` through a pivot, as in one step of Floyd-Warshall,
+//and reports whether the zone is non-empty. The diagonal is skipped, since a negative cycle through the pivot also
+//tightens some bound off the diagonal past its negation, which restrict reports as an empty zone.
+fn relax_through<T, D>(dbm: &mut D, to: &[Bound<T>], from: &[Bound<T>]) -> Result<bool, DbmError>
+where
+    T: Ord + Clone + Add<Output = T>,
+    D: DBM<T>,
+{
+    for (k, to_pivot) in to.iter().enumerate().filter(|(_, bound)| !bound.is_infinity()) {
+        for (l, from_pivot) in from.iter().enumerate().filter(|&(l, _)| l != k) {
+            let path = to_pivot.clone() + from_pivot.clone();
+            if path < D::try_get_bound(dbm, k, l)? {
+                D::try_restrict(dbm, k, l, path)?;
+                if D::is_empty(dbm) {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+    return Ok(true);
+}
+
+//the generic extrapolations widen bounds, which restrict cannot do, so the zone is rebuilt from init
+fn extrapolate_generic<T, D>(dbm: &mut D, lower: &[T], upper: &[T], diagonal: bool) -> Result<(), DbmError>
+where
+    T: Ord + Clone + Neg<Output = T> + Into<i64> + TryFrom<i64>,
+    D: DBM<T>,
+{
+    let dim = D::dimension(dbm);
+    error::check_dimensions(dim, lower.len())?;
+    error::check_dimensions(dim, upper.len())?;
+    if D::is_empty(dbm) {
+        return Ok(());
+    }
+    let matrix = extrapolation::extrapolate(&D::to_matrix(dbm), lower, upper, diagonal);
+    *dbm = D::try_from_matrix(matrix)?;
+    return Ok(());
 }
 
 #[cfg(test)]
@@ -38,6 +633,13 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                     let _ = <$type>::init(3);
                 }
 
+                #[test]
+                fn test_try_init_zero_dimension() {
+                    //not even the reference clock fits
+                    assert!(matches!(<$type>::try_init(0), Err(DbmError::ClockOutOfRange { clock: 0, dim: 0 })));
+                    assert!(matches!(<$type>::try_zero(0), Err(DbmError::ClockOutOfRange { clock: 0, dim: 0 })));
+                }
+
                 #[test]
                 fn test_relation_init() {
                     let x:$type = DBM::init(3);
@@ -46,6 +648,76 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                     assert_eq!(DBM::is_included_in(&y, &x), true);
                 }
 
+                #[test]
+                fn test_relation_equal() {
+                    let dim: usize = 10;
+                    let x:$type = DBM::init(dim);
+                    let y:$type = DBM::init(dim);
+                    assert_eq!(DBM::relation(&x, &y), Relation::Equal);
+                }
+
+                #[test]
+                fn test_relation_subset_superset() {
+                    let dim: usize = 10;
+                    let dbm_zero:$type = DBM::zero(dim);
+                    let dbm_init:$type = DBM::init(dim);
+                    assert_eq!(DBM::relation(&dbm_zero, &dbm_init), Relation::Subset);
+                    assert_eq!(DBM::relation(&dbm_init, &dbm_zero), Relation::Superset);
+                }
+
+                #[test]
+                fn test_relation_different() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let mut dbm2:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(10));
+                    DBM::restrict(&mut dbm2, 2, 0, Bound::LessThanEqual(10));
+                    assert_eq!(DBM::relation(&dbm, &dbm2), Relation::Different);
+                    assert_eq!(DBM::relation(&dbm2, &dbm), Relation::Different);
+                }
+
+                #[test]
+                fn test_relation_agrees_with_inclusion() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::assign(&mut dbm, 1, 10);
+                    let dbm2 = dbm.clone();
+                    DBM::shift(&mut dbm, 1, 10);
+                    let dbm3 = dbm.clone();
+                    DBM::future(&mut dbm);
+                    for x in [&dbm, &dbm2, &dbm3].iter() {
+                        for y in [&dbm, &dbm2, &dbm3].iter() {
+                            let expected = match (DBM::is_included_in(*x, *y), DBM::is_included_in(*y, *x)) {
+                                (true, true) => Relation::Equal,
+                                (true, false) => Relation::Subset,
+                                (false, true) => Relation::Superset,
+                                (false, false) => Relation::Different,
+                            };
+                            assert_eq!(DBM::relation(*x, *y), expected);
+                        }
+                    }
+                }
+
+                #[test]
+                fn test_relation_empty() {
+                    let dim: usize = 4;
+                    let zero:$type = DBM::zero(dim);
+                    let mut empty:$type = DBM::init(dim);
+                    DBM::restrict(&mut empty, 1, 0, Bound::LessThanEqual(2));
+                    DBM::restrict(&mut empty, 0, 1, Bound::LessThanEqual(-5));
+                    let mut empty2:$type = DBM::zero(dim);
+                    DBM::restrict(&mut empty2, 0, 2, Bound::LessThan(0)); //emptied through other bounds than empty
+                    assert_eq!(DBM::relation(&empty, &zero), Relation::Subset); //an empty zone is included in every zone
+                    assert_eq!(DBM::relation(&zero, &empty), Relation::Superset);
+                    assert_eq!(DBM::relation(&empty, &empty2), Relation::Equal);
+                    for x in [&empty, &empty2, &zero].iter() {
+                        for y in [&empty, &empty2, &zero].iter() {
+                            let expected = Relation::from_inclusions(DBM::is_included_in(*x, *y), DBM::is_included_in(*y, *x));
+                            assert_eq!(DBM::relation(*x, *y), expected);
+                        }
+                    }
+                }
+
                 #[test]
                 fn test_zero_close() {
                     let dim: usize = 10;
@@ -68,6 +740,52 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                     assert_eq!(DBM::is_included_in(&dbm_closed, &dbm_init), true); //this is a way to test if the dbm's are equal
                 }
 
+                #[test]
+                fn test_close_reports_emptiness() {
+                    let dim: usize = 4;
+                    let mut dbm:$type = DBM::init(dim);
+                    assert_eq!(DBM::close(&mut dbm), true);
+                    let mut dbm:$type = DBM::zero(dim);
+                    assert_eq!(DBM::close(&mut dbm), true);
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThan(0));
+                    assert_eq!(DBM::close(&mut dbm), false);
+                }
+
+                #[test]
+                fn test_close_ij_after_restrict() {
+                    let dim: usize = 5;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::future(&mut dbm);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(6));
+                    let mut closed = dbm.clone();
+                    DBM::close(&mut closed);
+                    assert_eq!(DBM::close_ij(&mut dbm, 1, 0), true);
+                    assert_eq!(DBM::relation(&dbm, &closed), Relation::Equal);
+                    assert_eq!(DBM::get_bound(&dbm, 3, 0), Bound::LessThanEqual(6)); //every clock is tied to x_1
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThan(-6));
+                    assert_eq!(DBM::close_ij(&mut dbm, 0, 2), false);
+                }
+
+                #[test]
+                fn test_close_clock_after_assign() {
+                    let dim: usize = 4;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::future(&mut dbm);
+                    DBM::assign(&mut dbm, 2, 3);
+                    let mut closed = dbm.clone();
+                    DBM::close(&mut closed);
+                    assert_eq!(DBM::close_clock(&mut dbm, 2), true);
+                    assert_eq!(DBM::relation(&dbm, &closed), Relation::Equal);
+                    assert_eq!(DBM::get_bound(&dbm, 2, 0), Bound::LessThanEqual(3));
+                }
+
+                #[test]
+                fn test_try_close_ij_out_of_range() {
+                    let mut dbm:$type = DBM::init(3);
+                    assert_eq!(DBM::try_close_ij(&mut dbm, 1, 3), Err(DbmError::ClockOutOfRange { clock: 3, dim: 3 }));
+                    assert_eq!(DBM::try_close_clock(&mut dbm, 4), Err(DbmError::ClockOutOfRange { clock: 4, dim: 3 }));
+                }
+
                 #[test]
                 fn test_init_different_from_zero() {
                     let dim: usize = 10;
@@ -83,7 +801,55 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                 fn test_zero() {
                     let dim: usize = 10;
                     let dbm:$type = DBM::zero(dim);
-                    assert_eq!(DBM::is_satisfied(&dbm, 1, 2, true, 0), false);
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 2, Bound::LessThan(0)), false);
+                }
+
+                #[test]
+                fn test_get_bound_init() {
+                    let dim: usize = 10;
+                    let dbm:$type = DBM::init(dim);
+                    assert_eq!(DBM::get_bound(&dbm, 0, 0), Bound::LessThanEqual(0));
+                    assert_eq!(DBM::get_bound(&dbm, 0, 1), Bound::LessThanEqual(0)); //clocks are never negative
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::Infinity);
+                    assert_eq!(DBM::get_bound(&dbm, 1, 2), Bound::Infinity);
+                }
+
+                #[test]
+                fn test_get_bound_restrict() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThan(10));
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThanEqual(-3));
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThan(10));
+                    assert_eq!(DBM::get_bound(&dbm, 0, 2), Bound::LessThanEqual(-3));
+                    assert_eq!(DBM::get_bound(&dbm, 1, 2), Bound::LessThan(7)); //closure derives x1 - x2 < 10 - 3
+                    assert_eq!(DBM::try_get_bound(&dbm, 0, dim), Err(DbmError::ClockOutOfRange { clock: dim, dim }));
+                }
+
+                #[test]
+                fn test_to_matrix_zero() {
+                    let dim: usize = 10;
+                    let dbm:$type = DBM::zero(dim);
+                    let matrix = DBM::to_matrix(&dbm);
+                    assert_eq!(matrix.len(), dim);
+                    for row in matrix {
+                        assert_eq!(row, vec![Bound::LessThanEqual(0); dim]);
+                    }
+                }
+
+                #[test]
+                fn test_to_matrix_restrict_different_order() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let mut dbm_reordered = dbm.clone();
+
+                    DBM::restrict(&mut dbm_reordered, 1, 2, Bound::LessThanEqual(10));
+                    DBM::restrict(&mut dbm_reordered, 1, 0, Bound::LessThan(15));
+
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThan(15));
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThanEqual(10));
+
+                    assert_eq!(DBM::to_matrix(&dbm), DBM::to_matrix(&dbm_reordered));
                 }
 
                 #[test]
@@ -101,7 +867,7 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                     let dim: usize = 10;
                     let mut dbm:$type = DBM::init(dim);
                     let dbm2:$type = DBM::init(dim);
-                    DBM::restrict(&mut dbm, 1, 0, false, 10);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(10));
                     assert_eq!(DBM::is_included_in(&dbm, &dbm2), true); //since dbm has been restricted, dbm2 should now include it, but not the other way around.
                     assert_eq!(DBM::is_included_in(&dbm2, &dbm), false);
                 }
@@ -110,32 +876,630 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                 fn test_restrict_with_satisfies() {
                     let dim: usize = 10;
                     let mut dbm:$type = DBM::init(dim);
-                    DBM::restrict(&mut dbm, 1, 0, false, 10);
-                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, false, 15), true);
-                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, false, 5), true);
-                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, false, -20), false); //this bound would be above the restrict, and as such, make the dbm inconsistent
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(10));
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, Bound::LessThanEqual(15)), true);
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, Bound::LessThanEqual(5)), true);
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, Bound::LessThanEqual(-20)), false); //this bound would be above the restrict, and as such, make the dbm inconsistent
                 }
 
                 #[test]
                 fn test_restrict_lower_bound() {
                     let dim: usize = 10;
                     let mut dbm:$type = DBM::init(dim);
-                    DBM::restrict(&mut dbm, 0, 1, false, -10); // This is a lower bound being set at 10, ie. clock 1 must have a greater value than 10
-                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, false, 15), true);
-                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, false, 5), false); // 5 is below lower bound, so not satisfied
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-10)); // This is a lower bound being set at 10, ie. clock 1 must have a greater value than 10
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, Bound::LessThanEqual(15)), true);
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, Bound::LessThanEqual(5)), false); // 5 is below lower bound, so not satisfied
                 }
 
                 #[test]
                 fn test_restrict_lower_closed() {
                     let dim: usize = 10;
                     let mut dbm:$type = DBM::init(dim);
-                    DBM::restrict(&mut dbm, 0, 1, false, -10);
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-10));
                     let dbm2 = dbm.clone();
                     DBM::close(&mut dbm);
                     assert_eq!(DBM::is_included_in(&dbm, &dbm2), true); //restrict should preserve closedness, so both should be included in each other.
                     assert_eq!(DBM::is_included_in(&dbm2, &dbm), true);
                 }
 
+                #[test]
+                fn test_not_empty() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let dbm_zero:$type = DBM::zero(dim);
+                    assert_eq!(DBM::is_empty(&dbm), false);
+                    assert_eq!(DBM::is_empty(&dbm_zero), false);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(5));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-5)); //clock 1 is exactly 5, which is still a valid point
+                    assert_eq!(DBM::is_empty(&dbm), false);
+                }
+
+                #[test]
+                fn test_empty_contradicting_bounds() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(5));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-10)); //clock 1 is at most 5 and at least 10
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                }
+
+                #[test]
+                fn test_empty_strict_bounds() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThan(5));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-5)); //clock 1 is below 5, but at least 5
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                }
+
+                #[test]
+                fn test_empty_negative_cycle() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThanEqual(-1));
+                    DBM::restrict(&mut dbm, 2, 3, Bound::LessThanEqual(-1));
+                    let mut dbm_cycle = dbm.clone();
+                    DBM::restrict(&mut dbm, 3, 1, Bound::LessThanEqual(2)); //the cycle 1 -> 2 -> 3 -> 1 has weight 0, so the zone is not empty
+                    assert_eq!(DBM::is_empty(&dbm), false);
+                    DBM::restrict(&mut dbm_cycle, 3, 1, Bound::LessThanEqual(1)); //but here it has weight -1
+                    assert_eq!(DBM::is_empty(&dbm_cycle), true);
+                }
+
+                #[test]
+                fn test_empty_after_assign() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::assign(&mut dbm, 1, 10);
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThan(10)); //clock 1 is 10 and clock 2 is 0, so x1 - x2 < 10 cannot hold
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                }
+
+                #[test]
+                fn test_future_within_is_future_then_restrict() {
+                    let dim: usize = 4;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::assign(&mut dbm, 2, 3);
+                    let invariant = [Constraint::new(1, 0, Bound::LessThanEqual(5)), Constraint::new(2, 1, Bound::LessThan(4)), Constraint::new(3, 0, Bound::Infinity)];
+                    let mut expected = dbm.clone();
+                    DBM::future(&mut expected);
+                    DBM::restrict(&mut expected, 1, 0, Bound::LessThanEqual(5));
+                    DBM::restrict(&mut expected, 2, 1, Bound::LessThan(4));
+                    DBM::future_within(&mut dbm, &invariant);
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThanEqual(5));
+                    assert_eq!(DBM::get_bound(&dbm, 2, 0), Bound::LessThanEqual(8));
+                }
+
+                #[test]
+                fn test_future_within_empty_invariant_is_future() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::zero(dim);
+                    let mut expected = dbm.clone();
+                    DBM::future(&mut expected);
+                    DBM::future_within(&mut dbm, &[]);
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                }
+
+                #[test]
+                fn test_future_within_violated_invariant() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::assign(&mut dbm, 1, 6);
+                    DBM::future_within(&mut dbm, &[Constraint::new(1, 0, Bound::LessThan(6))]); //the invariant is already violated
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                }
+
+                #[test]
+                fn test_past_within_is_past_then_restrict() {
+                    let dim: usize = 4;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-4));
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(6));
+                    DBM::restrict(&mut dbm, 2, 1, Bound::LessThanEqual(1));
+                    let guard = [Constraint::new(0, 1, Bound::LessThan(-1)), Constraint::new(3, 0, Bound::LessThanEqual(2))];
+                    let mut expected = dbm.clone();
+                    DBM::past(&mut expected);
+                    DBM::restrict(&mut expected, 0, 1, Bound::LessThan(-1));
+                    DBM::restrict(&mut expected, 3, 0, Bound::LessThanEqual(2));
+                    DBM::past_within(&mut dbm, &guard);
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                    assert_eq!(DBM::get_bound(&dbm, 0, 1), Bound::LessThan(-1));
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThanEqual(6));
+                }
+
+                #[test]
+                fn test_try_future_within_out_of_range_leaves_zone() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::zero(dim);
+                    let expected = dbm.clone();
+                    let invariant = [Constraint::new(1, 0, Bound::LessThanEqual(5)), Constraint::new(0, 7, Bound::LessThanEqual(0))];
+                    assert_eq!(DBM::try_future_within(&mut dbm, &invariant), Err(DbmError::ClockOutOfRange { clock: 7, dim: 3 }));
+                    assert_eq!(DBM::try_past_within(&mut dbm, &invariant), Err(DbmError::ClockOutOfRange { clock: 7, dim: 3 }));
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                }
+
+                //restricts init by the minimal constraints of `dbm`, which should rebuild it
+                fn rebuild(dbm: &$type) -> $type {
+                    let mut rebuilt:$type = DBM::init(DBM::dimension(dbm));
+                    for constraint in minimal_constraints(dbm) {
+                        assert!(!constraint.bound.is_infinity(), "minimal constraints are never infinite");
+                        DBM::restrict(&mut rebuilt, constraint.i, constraint.j, constraint.bound);
+                    }
+                    return rebuilt;
+                }
+
+                #[test]
+                fn test_minimal_constraints_init_and_zero() {
+                    let dim: usize = 5;
+                    let init:$type = DBM::init(dim);
+                    let zero:$type = DBM::zero(dim);
+                    assert_eq!(minimal_constraints(&init).count(), 0);
+                    assert_eq!(minimal_constraints(&zero).count(), dim - 1); //one cycle through every clock
+                    assert_eq!(DBM::relation(&rebuild(&init), &init), Relation::Equal);
+                    assert_eq!(DBM::relation(&rebuild(&zero), &zero), Relation::Equal);
+                }
+
+                #[test]
+                fn test_minimal_constraints_drop_implied() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(3));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-1));
+                    DBM::restrict(&mut dbm, 2, 0, Bound::LessThan(4));
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThanEqual(-2));
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThanEqual(5)); //implied by the bounds on single clocks
+                    let constraints: Vec<_> = minimal_constraints(&dbm).collect();
+                    assert_eq!(constraints.len(), 4);
+                    assert_eq!(constraints.contains(&Constraint::new(2, 0, Bound::LessThan(4))), true);
+                    assert_eq!(DBM::relation(&rebuild(&dbm), &dbm), Relation::Equal);
+                }
+
+                #[test]
+                fn test_minimal_constraints_rebuild() {
+                    let dim: usize = 6;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::future(&mut dbm);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThan(8));
+                    DBM::assign(&mut dbm, 2, 0);
+                    DBM::future(&mut dbm);
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThanEqual(-1));
+                    DBM::copy(&mut dbm, 3, 2); //x_3 and x_2 are equal, a zero cycle
+                    DBM::shift(&mut dbm, 4, 2); //moves x_4 up by 2, and x_4 was equal to x_5, so their difference is fixed at 2
+                    DBM::restrict(&mut dbm, 5, 1, Bound::LessThan(3));
+                    assert_eq!(DBM::is_empty(&dbm), false);
+                    assert_eq!(DBM::relation(&rebuild(&dbm), &dbm), Relation::Equal);
+                }
+
+                #[test]
+                fn test_minimal_constraints_empty() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(2));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-5));
+                    assert_eq!(DBM::is_empty(&rebuild(&dbm)), true);
+                }
+
+                #[test]
+                fn test_restrict_infinity_is_identity() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(4));
+                    let expected = dbm.clone();
+                    DBM::restrict(&mut dbm, 1, 0, Bound::Infinity);
+                    DBM::restrict(&mut dbm, 2, 1, Bound::Infinity);
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                    assert_eq!(DBM::try_restrict(&mut dbm, 3, 0, Bound::Infinity), Err(DbmError::ClockOutOfRange { clock: 3, dim: 3 }));
+                }
+
+                #[test]
+                fn test_is_satisfied_strictness_at_the_edge() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(5)); //x_1 can be exactly 5
+                    assert_eq!(DBM::is_satisfied(&dbm, 0, 1, Bound::LessThanEqual(-5)), true);
+                    assert_eq!(DBM::is_satisfied(&dbm, 0, 1, Bound::LessThan(-5)), false);
+                    DBM::restrict(&mut dbm, 2, 0, Bound::LessThan(5)); //x_2 stays below 5
+                    assert_eq!(DBM::is_satisfied(&dbm, 0, 2, Bound::LessThanEqual(-5)), false);
+                    assert_eq!(DBM::is_satisfied(&dbm, 0, 2, Bound::LessThan(-4)), true);
+                }
+
+                #[test]
+                fn test_is_satisfied_infinity() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::zero(dim);
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, Bound::Infinity), true);
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThan(0));
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                    assert_eq!(DBM::is_satisfied(&dbm, 1, 0, Bound::Infinity), false);
+                }
+
+                #[test]
+                fn test_intersect_with_init() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let mut dbm_restricted:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm_restricted, 1, 0, Bound::LessThanEqual(10));
+                    DBM::restrict(&mut dbm_restricted, 2, 1, Bound::LessThan(5));
+                    DBM::intersect(&mut dbm, &dbm_restricted); //intersecting with a tighter zone yields the tighter zone
+                    assert_eq!(DBM::is_included_in(&dbm, &dbm_restricted), true);
+                    assert_eq!(DBM::is_included_in(&dbm_restricted, &dbm), true);
+                }
+
+                #[test]
+                fn test_intersect_commutative() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let mut dbm2:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(10));
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThan(3));
+                    DBM::restrict(&mut dbm2, 0, 1, Bound::LessThanEqual(-5));
+                    DBM::restrict(&mut dbm2, 2, 0, Bound::LessThanEqual(20));
+                    let mut dbm_left = dbm.clone();
+                    let mut dbm_right = dbm2.clone();
+                    DBM::intersect(&mut dbm_left, &dbm2);
+                    DBM::intersect(&mut dbm_right, &dbm);
+                    assert_eq!(DBM::is_included_in(&dbm_left, &dbm_right), true);
+                    assert_eq!(DBM::is_included_in(&dbm_right, &dbm_left), true);
+                    assert_eq!(DBM::is_included_in(&dbm_left, &dbm), true); //the intersection is included in both operands
+                    assert_eq!(DBM::is_included_in(&dbm_left, &dbm2), true);
+                    assert_eq!(DBM::is_included_in(&dbm, &dbm_left), false);
+                    assert_eq!(DBM::is_included_in(&dbm2, &dbm_left), false);
+                }
+
+                #[test]
+                fn test_intersect_disjoint() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let mut dbm2:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(5));
+                    DBM::restrict(&mut dbm2, 0, 1, Bound::LessThan(-5)); //clock 1 is at most 5 in dbm, and above 5 in dbm2
+                    assert_eq!(DBM::is_empty(&dbm), false);
+                    DBM::intersect(&mut dbm, &dbm2);
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                }
+
+                #[test]
+                fn test_try_intersect_dimension_mismatch() {
+                    let mut dbm:$type = DBM::init(10);
+                    let dbm2:$type = DBM::init(5);
+                    assert_eq!(DBM::try_intersect(&mut dbm, &dbm2), Err(DbmError::DimensionMismatch { lhs: 10, rhs: 5 }));
+                }
+
+                #[test]
+                fn test_convex_hull_includes_both() {
+                    let dim: usize = 4;
+                    let mut dbm:$type = DBM::init(dim);
+                    let mut dbm2:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(2));
+                    DBM::restrict(&mut dbm, 2, 1, Bound::LessThan(1));
+                    DBM::restrict(&mut dbm2, 0, 1, Bound::LessThanEqual(-6));
+                    DBM::restrict(&mut dbm2, 1, 0, Bound::LessThanEqual(8));
+                    DBM::restrict(&mut dbm2, 3, 0, Bound::LessThanEqual(4));
+                    let mut hull = dbm.clone();
+                    DBM::convex_hull(&mut hull, &dbm2);
+                    assert_eq!(DBM::is_included_in(&dbm, &hull), true);
+                    assert_eq!(DBM::is_included_in(&dbm2, &hull), true);
+                    assert_eq!(DBM::get_bound(&hull, 1, 0), Bound::LessThanEqual(8));
+                    assert_eq!(DBM::get_bound(&hull, 0, 1), Bound::LessThanEqual(0));
+                    assert_eq!(DBM::get_bound(&hull, 3, 0), Bound::Infinity);
+                    let mut gap:$type = DBM::init(dim); //the hull also covers valuations between the two zones
+                    DBM::restrict(&mut gap, 1, 0, Bound::LessThanEqual(4));
+                    DBM::restrict(&mut gap, 0, 1, Bound::LessThanEqual(-4));
+                    DBM::intersect(&mut gap, &hull);
+                    assert_eq!(DBM::is_empty(&gap), false);
+                }
+
+                #[test]
+                fn test_convex_hull_keeps_diagonals() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::zero(dim);
+                    let mut dbm2:$type = DBM::zero(dim);
+                    DBM::shift(&mut dbm, 1, 1);
+                    DBM::shift(&mut dbm, 2, 1);
+                    DBM::shift(&mut dbm2, 1, 5);
+                    DBM::shift(&mut dbm2, 2, 5);
+                    DBM::convex_hull(&mut dbm, &dbm2); //both clocks stay equal in the hull
+                    assert_eq!(DBM::get_bound(&dbm, 1, 2), Bound::LessThanEqual(0));
+                    assert_eq!(DBM::get_bound(&dbm, 2, 1), Bound::LessThanEqual(0));
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThanEqual(5));
+                    assert_eq!(DBM::get_bound(&dbm, 0, 2), Bound::LessThanEqual(-1));
+                }
+
+                #[test]
+                fn test_convex_hull_strictness() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    let mut dbm2:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThan(3));
+                    DBM::restrict(&mut dbm2, 1, 0, Bound::LessThanEqual(3));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-1));
+                    DBM::restrict(&mut dbm2, 0, 1, Bound::LessThan(-1));
+                    DBM::convex_hull(&mut dbm, &dbm2); //the non-strict bound is the weaker one
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThanEqual(3));
+                    assert_eq!(DBM::get_bound(&dbm, 0, 1), Bound::LessThanEqual(-1));
+                }
+
+                #[test]
+                fn test_convex_hull_with_empty() {
+                    let dim: usize = 3;
+                    let mut empty:$type = DBM::init(dim);
+                    DBM::restrict(&mut empty, 1, 0, Bound::LessThanEqual(2));
+                    DBM::restrict(&mut empty, 0, 1, Bound::LessThanEqual(-5));
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 2, 0, Bound::LessThanEqual(7));
+                    let mut hull = dbm.clone();
+                    DBM::convex_hull(&mut hull, &empty);
+                    assert_eq!(DBM::relation(&hull, &dbm), Relation::Equal);
+                    let mut hull = empty.clone();
+                    DBM::convex_hull(&mut hull, &dbm);
+                    assert_eq!(DBM::relation(&hull, &dbm), Relation::Equal);
+                }
+
+                #[test]
+                fn test_try_convex_hull_dimension_mismatch() {
+                    let mut dbm:$type = DBM::init(10);
+                    let dbm2:$type = DBM::init(5);
+                    assert_eq!(DBM::try_convex_hull(&mut dbm, &dbm2), Err(DbmError::DimensionMismatch { lhs: 10, rhs: 5 }));
+                }
+
+                #[test]
+                fn test_contains_point() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(3));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-1));
+                    DBM::restrict(&mut dbm, 2, 1, Bound::LessThan(2));
+                    assert_eq!(DBM::contains_point(&dbm, &points(&[0, 2, 3])), true);
+                    assert_eq!(DBM::contains_point(&dbm, &points(&[0, 1, 0])), true);
+                    assert_eq!(DBM::contains_point(&dbm, &points(&[0, 2, 4])), false); //x_2 - x_1 < 2 is strict
+                    assert_eq!(DBM::contains_point(&dbm, &points(&[0, 4, 0])), false);
+                    assert_eq!(DBM::contains_point(&dbm, &[Rational::from_integer(0), Rational::new(1, 2), Rational::from_integer(0)]), false);
+                    assert_eq!(DBM::contains_point(&dbm, &[Rational::from_integer(0), Rational::new(5, 2), Rational::new(449, 100)]), true);
+                }
+
+                #[test]
+                fn test_contains_point_empty() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(2));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-5));
+                    assert_eq!(DBM::contains_point(&dbm, &points(&[0, 2, 0])), false);
+                    assert_eq!(DBM::contains_point(&dbm, &points(&[0, 5, 0])), false);
+                }
+
+                #[test]
+                fn test_try_contains_point_dimension_mismatch() {
+                    let dbm:$type = DBM::init(3);
+                    assert_eq!(DBM::try_contains_point(&dbm, &points(&[0, 1])), Err(DbmError::DimensionMismatch { lhs: 3, rhs: 2 }));
+                }
+
+                #[test]
+                fn test_sample_point_init_and_zero() {
+                    let dim: usize = 4;
+                    let init:$type = DBM::init(dim);
+                    let zero:$type = DBM::zero(dim);
+                    assert_eq!(DBM::sample_point(&init), Some(points(&[0, 0, 0, 0])));
+                    assert_eq!(DBM::sample_point(&zero), Some(points(&[0, 0, 0, 0])));
+                }
+
+                #[test]
+                fn test_sample_point_strict_interval() {
+                    let dim: usize = 2;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThan(2));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThan(-1)); //no integer lies strictly between 1 and 2
+                    assert_eq!(DBM::sample_point(&dbm), Some(vec![Rational::from_integer(0), Rational::new(3, 2)]));
+                }
+
+                #[test]
+                fn test_sample_point_empty() {
+                    let dim: usize = 3;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThan(0));
+                    DBM::restrict(&mut dbm, 2, 1, Bound::LessThanEqual(0));
+                    assert_eq!(DBM::sample_point(&dbm), None);
+                }
+
+                #[test]
+                fn test_sample_point_is_contained() {
+                    let dim: usize = 4;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::future(&mut dbm);
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThan(-2));
+                    DBM::assign(&mut dbm, 2, 1);
+                    DBM::future(&mut dbm);
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThan(2));
+                    DBM::restrict(&mut dbm, 3, 0, Bound::LessThan(4));
+                    let point = DBM::sample_point(&dbm).unwrap();
+                    assert_eq!(point[0], Rational::from_integer(0));
+                    assert_eq!(DBM::contains_point(&dbm, &point), true);
+                }
+
+                #[test]
+                fn test_from_matrix_round_trip() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThan(10));
+                    DBM::restrict(&mut dbm, 2, 3, Bound::LessThanEqual(-4));
+                    let dbm2:$type = DBM::from_matrix(DBM::to_matrix(&dbm));
+                    assert_eq!(DBM::relation(&dbm, &dbm2), Relation::Equal);
+                }
+
+                #[test]
+                fn test_extrapolate_max_bounds() {
+                    let max_bounds = [0, 10, 10, 10];
+                    let mut dbm:$type = DBM::zero(4);
+                    DBM::future(&mut dbm);
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-15)); //all clocks are equal and at least 15, above their maximal constant
+                    let mut dbm_m = dbm.clone();
+                    DBM::extrapolate_max_bounds(&mut dbm_m, &max_bounds);
+                    let mut dbm_m_plus = dbm.clone();
+                    DBM::extrapolate_diagonal_max_bounds(&mut dbm_m_plus, &max_bounds);
+
+                    assert_eq!(DBM::relation(&dbm, &dbm_m), Relation::Subset); //extrapolation only ever makes zones coarser
+                    assert_eq!(DBM::relation(&dbm_m, &dbm_m_plus), Relation::Subset); //and the diagonal variant drops x1 == x2 as well
+                    assert_eq!(DBM::get_bound(&dbm_m, 0, 1), Bound::LessThan(-10));
+                    assert_eq!(DBM::get_bound(&dbm_m, 1, 2), Bound::LessThanEqual(0));
+                    assert_eq!(DBM::get_bound(&dbm_m_plus, 1, 2), Bound::Infinity);
+                    assert_guards_agree(&dbm, &dbm_m, &max_bounds, &max_bounds);
+                    assert_guards_agree(&dbm, &dbm_m_plus, &max_bounds, &max_bounds);
+                }
+
+                #[test]
+                fn test_extrapolate_below_max_bounds_is_identity() {
+                    let mut dbm:$type = DBM::init(4);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(5));
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThan(-3));
+                    DBM::restrict(&mut dbm, 3, 1, Bound::LessThanEqual(2));
+                    let mut dbm_m = dbm.clone();
+                    DBM::extrapolate_diagonal_max_bounds(&mut dbm_m, &[0, 20, 20, 20]);
+                    assert_eq!(DBM::to_matrix(&dbm), DBM::to_matrix(&dbm_m));
+                }
+
+                #[test]
+                fn test_extrapolate_lu_bounds() {
+                    let lower = [0, 5, 5, 5];
+                    let upper = [0, 25, 10, 10];
+                    let mut dbm:$type = DBM::init(4);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(30));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-2));
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThanEqual(-20));
+                    DBM::restrict(&mut dbm, 3, 0, Bound::LessThan(4));
+                    let mut dbm_lu = dbm.clone();
+                    DBM::extrapolate_lu_bounds(&mut dbm_lu, &lower, &upper);
+                    let mut dbm_lu_plus = dbm.clone();
+                    DBM::extrapolate_diagonal_lu_bounds(&mut dbm_lu_plus, &lower, &upper);
+                    let mut dbm_m = dbm.clone();
+                    DBM::extrapolate_max_bounds(&mut dbm_m, &upper); //the upper bounds are the per-clock maxima here
+
+                    assert_eq!(DBM::relation(&dbm, &dbm_m), Relation::Subset);
+                    assert_eq!(DBM::relation(&dbm_m, &dbm_lu), Relation::Subset); //separate lower and upper constants abstract more
+                    assert_eq!(DBM::is_included_in(&dbm_lu, &dbm_lu_plus), true);
+                    assert_eq!(DBM::get_bound(&dbm_lu, 1, 0), Bound::Infinity); //30 is above the lower bound constant 5
+                    assert_eq!(DBM::get_bound(&dbm_lu, 0, 2), Bound::LessThan(-10));
+                    assert_guards_agree(&dbm, &dbm_lu, &lower, &upper);
+                    assert_guards_agree(&dbm, &dbm_lu_plus, &lower, &upper);
+                }
+
+                #[test]
+                fn test_extrapolate_strict_lower_bounds() {
+                    //x1 > 10 does not lie above the maximal constant 10, but the next tighter bound x1 >= 11 does
+                    let max_bounds = [0, 10, 10, 10];
+                    let mut strict:$type = DBM::init(4);
+                    DBM::restrict(&mut strict, 0, 1, Bound::LessThan(-10));
+                    DBM::restrict(&mut strict, 1, 2, Bound::LessThanEqual(2));
+                    let mut non_strict:$type = DBM::init(4);
+                    DBM::restrict(&mut non_strict, 0, 1, Bound::LessThanEqual(-11));
+                    DBM::restrict(&mut non_strict, 1, 2, Bound::LessThanEqual(2));
+                    let (mut strict_m, mut non_strict_m) = (strict.clone(), non_strict.clone());
+                    DBM::extrapolate_max_bounds(&mut strict_m, &max_bounds);
+                    DBM::extrapolate_max_bounds(&mut non_strict_m, &max_bounds);
+                    let (mut strict_m_plus, mut non_strict_m_plus) = (strict.clone(), non_strict.clone());
+                    DBM::extrapolate_diagonal_max_bounds(&mut strict_m_plus, &max_bounds);
+                    DBM::extrapolate_diagonal_max_bounds(&mut non_strict_m_plus, &max_bounds);
+
+                    assert_eq!(DBM::get_bound(&strict_m, 0, 1), Bound::LessThan(-10));
+                    assert_eq!(DBM::get_bound(&non_strict_m, 0, 1), Bound::LessThan(-10));
+                    assert_eq!(DBM::get_bound(&strict_m_plus, 1, 2), Bound::LessThanEqual(2));
+                    assert_eq!(DBM::get_bound(&non_strict_m_plus, 1, 2), Bound::Infinity);
+                    assert_guards_agree(&strict, &strict_m_plus, &max_bounds, &max_bounds);
+                    assert_guards_agree(&non_strict, &non_strict_m_plus, &max_bounds, &max_bounds);
+                }
+
+                #[test]
+                fn test_extrapolate_wrong_length() {
+                    let mut dbm:$type = DBM::init(4);
+                    assert_eq!(DBM::try_extrapolate_max_bounds(&mut dbm, &[0, 10]), Err(DbmError::DimensionMismatch { lhs: 4, rhs: 2 }));
+                }
+
+                #[test]
+                fn test_remove_clock_keeps_constraints() {
+                    let dim: usize = 5;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThanEqual(3));
+                    DBM::restrict(&mut dbm, 3, 0, Bound::LessThan(5));
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThanEqual(-1));
+                    DBM::restrict(&mut dbm, 4, 3, Bound::LessThanEqual(2));
+                    let matrix = DBM::to_matrix(&dbm);
+                    DBM::remove_clock(&mut dbm, 2);
+                    assert_eq!(DBM::dimension(&dbm), dim - 1);
+                    let retained = [0, 1, 3, 4]; //old clock of each new clock
+                    for i in 0..dim - 1 {
+                        for j in 0..dim - 1 {
+                            assert_eq!(DBM::get_bound(&dbm, i, j), matrix[retained[i]][retained[j]]);
+                        }
+                    }
+                }
+
+                #[test]
+                fn test_add_clock() {
+                    let dim: usize = 4;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::future(&mut dbm);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(10));
+                    let dbm_orig = dbm.clone();
+                    let clock = DBM::add_clock(&mut dbm);
+                    assert_eq!(clock, dim);
+                    assert_eq!(DBM::dimension(&dbm), dim + 1);
+                    assert_eq!(DBM::get_bound(&dbm, clock, 0), Bound::Infinity); //the new clock is unconstrained
+                    assert_eq!(DBM::get_bound(&dbm, 0, clock), Bound::LessThanEqual(0));
+                    assert_eq!(DBM::get_bound(&dbm, 1, 2), Bound::LessThanEqual(0)); //while the old clocks are still equal
+                    DBM::remove_clock(&mut dbm, clock);
+                    assert_eq!(DBM::relation(&dbm, &dbm_orig), Relation::Equal);
+                }
+
+                #[test]
+                fn test_resize_remap() {
+                    let mut dbm:$type = DBM::init(4);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(10));
+                    DBM::restrict(&mut dbm, 0, 3, Bound::LessThan(-2));
+                    let mut dbm_swapped = dbm.clone();
+                    DBM::resize(&mut dbm_swapped, &[Some(0), Some(3), Some(2), Some(1)]); //swap clocks 1 and 3
+                    assert_eq!(DBM::get_bound(&dbm_swapped, 3, 0), Bound::LessThanEqual(10));
+                    assert_eq!(DBM::get_bound(&dbm_swapped, 0, 1), Bound::LessThan(-2));
+                    DBM::resize(&mut dbm_swapped, &[Some(0), Some(3), Some(2), Some(1)]);
+                    assert_eq!(DBM::relation(&dbm, &dbm_swapped), Relation::Equal);
+
+                    let mut dbm_duplicated = dbm.clone();
+                    DBM::resize(&mut dbm_duplicated, &[Some(0), Some(1), Some(1)]); //the new clock 2 is a copy of clock 1
+                    assert_eq!(DBM::get_bound(&dbm_duplicated, 1, 2), Bound::LessThanEqual(0));
+                    assert_eq!(DBM::get_bound(&dbm_duplicated, 2, 0), Bound::LessThanEqual(10));
+                }
+
+                #[test]
+                fn test_resize_empty() {
+                    let mut dbm:$type = DBM::init(4);
+                    DBM::restrict(&mut dbm, 3, 0, Bound::LessThanEqual(5));
+                    DBM::restrict(&mut dbm, 0, 3, Bound::LessThanEqual(-10));
+                    DBM::remove_clock(&mut dbm, 3);
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                }
+
+                #[test]
+                fn test_resize_empty_to_reference_clock() {
+                    let mut dbm:$type = DBM::init(2);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(2));
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-5));
+                    assert_eq!(DBM::try_remove_clock(&mut dbm, 1), Err(DbmError::InconsistentZone)); //the reference clock alone always has a valuation
+                    assert_eq!(DBM::try_resize(&mut dbm, &[Some(0)]), Err(DbmError::InconsistentZone));
+                    assert_eq!(DBM::dimension(&dbm), 2);
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                    let mut dbm:$type = DBM::init(2);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(2));
+                    DBM::remove_clock(&mut dbm, 1);
+                    assert_eq!(DBM::dimension(&dbm), 1);
+                    assert_eq!(DBM::is_empty(&dbm), false);
+                }
+
+                #[test]
+                fn test_try_resize_invalid() {
+                    let mut dbm:$type = DBM::init(4);
+                    assert_eq!(DBM::try_resize(&mut dbm, &[Some(0), Some(4)]), Err(DbmError::ClockOutOfRange { clock: 4, dim: 4 }));
+                    assert!(DBM::try_resize(&mut dbm, &[Some(1), Some(0)]).is_err());
+                    assert!(DBM::try_remove_clock(&mut dbm, 0).is_err());
+                    assert_eq!(DBM::dimension(&dbm), 4);
+                }
+
                 #[test]
                 fn test_free() {
                     let dim: usize = 10;
@@ -237,19 +1601,67 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                 }
 
 
+                #[test]
+                fn test_update() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::zero(dim);
+                    DBM::assign(&mut dbm, 2, 10);
+                    let mut dbm_chained = dbm.clone();
+                    DBM::update(&mut dbm, 1, 2, 5); //x1 := x2 + 5
+                    DBM::copy(&mut dbm_chained, 1, 2);
+                    DBM::shift(&mut dbm_chained, 1, 5);
+                    assert_eq!(DBM::relation(&dbm, &dbm_chained), Relation::Equal);
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThanEqual(15));
+                    assert_eq!(DBM::get_bound(&dbm, 1, 2), Bound::LessThanEqual(5));
+                }
+
+                #[test]
+                fn test_update_same_clock() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::assign(&mut dbm, 1, 10);
+                    let mut dbm_shifted = dbm.clone();
+                    DBM::update(&mut dbm, 1, 1, 10); //x1 := x1 + 10 is a shift
+                    DBM::shift(&mut dbm_shifted, 1, 10);
+                    assert_eq!(DBM::relation(&dbm, &dbm_shifted), Relation::Equal);
+                }
+
+                #[test]
+                fn test_reset_many() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    DBM::future(&mut dbm);
+                    let mut dbm_chained = dbm.clone();
+                    DBM::reset_many(&mut dbm, &[(1, 0), (3, 5), (4, 0)]);
+                    DBM::assign(&mut dbm_chained, 1, 0);
+                    DBM::assign(&mut dbm_chained, 3, 5);
+                    DBM::assign(&mut dbm_chained, 4, 0);
+                    assert_eq!(DBM::relation(&dbm, &dbm_chained), Relation::Equal);
+                    assert_eq!(DBM::get_bound(&dbm, 3, 1), Bound::LessThanEqual(5));
+                }
+
+                #[test]
+                fn test_try_reset_many_out_of_range() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let dbm_orig = dbm.clone();
+                    assert_eq!(DBM::try_reset_many(&mut dbm, &[(1, 0), (dim, 0)]), Err(DbmError::ClockOutOfRange { clock: dim, dim }));
+                    assert_eq!(DBM::relation(&dbm, &dbm_orig), Relation::Equal); //clock 1 must not have been reset either
+                }
+
                 #[test]
                 fn test_restrict_different_order() {
                     let dim: usize = 10;
                     let mut dbm:$type = DBM::init(dim);
                     let mut dbm_reordered = dbm.clone();
 
-                    DBM::restrict(&mut dbm_reordered, 1, 2, false, 10);
-                    DBM::restrict(&mut dbm_reordered, 1, 0, false, 15);
-                    DBM::restrict(&mut dbm_reordered, 2, 3, false, 20);
+                    DBM::restrict(&mut dbm_reordered, 1, 2, Bound::LessThanEqual(10));
+                    DBM::restrict(&mut dbm_reordered, 1, 0, Bound::LessThanEqual(15));
+                    DBM::restrict(&mut dbm_reordered, 2, 3, Bound::LessThanEqual(20));
 
-                    DBM::restrict(&mut dbm, 2, 3, false, 20);
-                    DBM::restrict(&mut dbm, 1, 2, false, 10);
-                    DBM::restrict(&mut dbm, 1, 0, false, 15);
+                    DBM::restrict(&mut dbm, 2, 3, Bound::LessThanEqual(20));
+                    DBM::restrict(&mut dbm, 1, 2, Bound::LessThanEqual(10));
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(15));
 
                     assert_eq!(DBM::is_included_in(&dbm, &dbm_reordered), true); //order of restricts shouldn't matter for equality, dbms should be equal
                     assert_eq!(DBM::is_included_in(&dbm_reordered, &dbm), true);
@@ -272,6 +1684,179 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
                     assert_eq!(DBM::is_included_in(&dbm, &dbm_redundant), true); //redundant frees shouldn't do anything, dbms should be equal
                     assert_eq!(DBM::is_included_in(&dbm_redundant, &dbm), true);
                 }
+
+                #[test]
+                fn test_dimension() {
+                    let dim: usize = 10;
+                    let dbm:$type = DBM::init(dim);
+                    assert_eq!(DBM::dimension(&dbm), dim);
+                }
+
+                #[test]
+                fn test_large_dimension_is_exact_or_rejected() {
+                    for &dim in [300, 600].iter() {
+                        match <$type as DBM<_>>::try_zero(dim) {
+                            Ok(dbm) => assert_eq!(DBM::dimension(&dbm), dim), //a backend may refuse a dimension, but never shrink it
+                            Err(error) => assert!(matches!(error, DbmError::DimensionTooLarge { .. })),
+                        }
+                    }
+                }
+
+                #[test]
+                fn test_try_clock_out_of_range() {
+                    let dim: usize = 10;
+                    let mut dbm:$type = DBM::init(dim);
+                    let dbm2 = dbm.clone();
+                    assert_eq!(DBM::try_restrict(&mut dbm, 1, dim, Bound::LessThanEqual(10)), Err(DbmError::ClockOutOfRange { clock: dim, dim }));
+                    assert_eq!(DBM::try_free(&mut dbm, dim), Err(DbmError::ClockOutOfRange { clock: dim, dim }));
+                    assert_eq!(DBM::try_assign(&mut dbm, dim + 5, 10), Err(DbmError::ClockOutOfRange { clock: dim + 5, dim }));
+                    assert_eq!(DBM::try_copy(&mut dbm, 1, dim), Err(DbmError::ClockOutOfRange { clock: dim, dim }));
+                    assert_eq!(DBM::try_shift(&mut dbm, dim, 10), Err(DbmError::ClockOutOfRange { clock: dim, dim }));
+                    assert_eq!(DBM::try_is_satisfied(&dbm, dim, 0, Bound::LessThanEqual(10)), Err(DbmError::ClockOutOfRange { clock: dim, dim }));
+                    assert_eq!(DBM::is_included_in(&dbm, &dbm2), true); //failed operations must leave the dbm untouched
+                    assert_eq!(DBM::is_included_in(&dbm2, &dbm), true);
+                }
+
+                #[test]
+                fn test_try_included_in_dimension_mismatch() {
+                    let dbm:$type = DBM::init(10);
+                    let dbm2:$type = DBM::init(5);
+                    assert_eq!(DBM::try_is_included_in(&dbm, &dbm2), Err(DbmError::DimensionMismatch { lhs: 10, rhs: 5 }));
+                }
+
+                #[test]
+                #[should_panic]
+                fn test_clock_out_of_range_panics() {
+                    let mut dbm:$type = DBM::init(10);
+                    DBM::free(&mut dbm, 10);
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(test)]
+macro_rules! generate_overflow_tests {
+    ($($name:ident: $type:ty, $constant:ty, $limit:expr;)*) => {
+        $(
+            mod $name {
+                use super::*;
+
+                fn limit() -> $constant {
+                    return $limit as $constant;
+                }
+
+                #[test]
+                fn test_constants_at_the_limit() {
+                    let mut dbm:$type = DBM::init(3);
+                    assert_eq!(DBM::try_restrict(&mut dbm, 1, 0, Bound::LessThanEqual(limit())), Ok(()));
+                    assert_eq!(DBM::try_restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-limit())), Ok(()));
+                    assert_eq!(DBM::try_assign(&mut dbm, 2, limit()), Ok(()));
+                    assert_eq!(DBM::get_bound(&dbm, 0, 1), Bound::LessThanEqual(-limit()));
+                    assert_eq!(DBM::get_bound(&dbm, 2, 0), Bound::LessThanEqual(limit()));
+                    assert_eq!(DBM::try_is_satisfied(&dbm, 2, 1, Bound::LessThanEqual(0)), Ok(true));
+                }
+
+                #[test]
+                fn test_constants_beyond_the_limit_leave_zone() {
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(5));
+                    let expected = dbm.clone();
+                    assert_eq!(DBM::try_restrict(&mut dbm, 1, 0, Bound::LessThanEqual(limit() + 1)), Err(DbmError::Overflow));
+                    assert_eq!(DBM::try_restrict(&mut dbm, 0, 2, Bound::LessThan(-limit() - 1)), Err(DbmError::Overflow));
+                    assert_eq!(DBM::try_is_satisfied(&dbm, 2, 0, Bound::LessThanEqual(limit() + 1)), Err(DbmError::Overflow));
+                    assert_eq!(DBM::try_assign(&mut dbm, 2, limit() + 1), Err(DbmError::Overflow));
+                    assert_eq!(DBM::try_shift(&mut dbm, 1, -limit() - 1), Err(DbmError::Overflow));
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                }
+
+                #[test]
+                fn test_closure_sum_overflows() {
+                    //x1 <= limit and x2 - x1 <= limit would bound x2 by twice the limit
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(limit()));
+                    let expected = dbm.clone();
+                    assert_eq!(DBM::try_restrict(&mut dbm, 2, 1, Bound::LessThanEqual(limit())), Err(DbmError::Overflow));
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                    //and x1 >= limit with x1 - x2 <= -1 would bound x2 below by one more than the limit
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-limit()));
+                    let expected = dbm.clone();
+                    assert_eq!(DBM::try_restrict(&mut dbm, 1, 2, Bound::LessThanEqual(-1)), Err(DbmError::Overflow));
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                }
+
+                #[test]
+                fn test_shift_chain_overflows() {
+                    //test_multiple_shift, continued until the clock passes the limit
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::assign(&mut dbm, 1, limit() - 2);
+                    DBM::shift(&mut dbm, 1, 1);
+                    DBM::shift(&mut dbm, 1, 1);
+                    assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThanEqual(limit()));
+                    let expected = dbm.clone();
+                    assert_eq!(DBM::try_shift(&mut dbm, 1, 1), Err(DbmError::Overflow));
+                    assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+                }
+
+                #[test]
+                fn test_update_overflows() {
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::assign(&mut dbm, 2, limit());
+                    assert_eq!(DBM::try_update(&mut dbm, 1, 2, 1), Err(DbmError::Overflow));
+                }
+
+                #[test]
+                fn test_intersect_overflows() {
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(limit()));
+                    let mut other:$type = DBM::init(3);
+                    DBM::restrict(&mut other, 2, 1, Bound::LessThanEqual(limit()));
+                    assert_eq!(DBM::try_intersect(&mut dbm, &other), Err(DbmError::Overflow));
+                }
+
+                #[test]
+                fn test_future_within_overflows() {
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::restrict(&mut dbm, 0, 1, Bound::LessThanEqual(-limit()));
+                    let invariant = [Constraint::new(1, 2, Bound::LessThanEqual(-1))];
+                    assert_eq!(DBM::try_future_within(&mut dbm, &invariant), Err(DbmError::Overflow));
+                }
+
+                #[test]
+                fn test_emptying_restrict_does_not_overflow() {
+                    //closing x1 <= limit, x2 >= limit and x2 - x1 <= -limit would bound x0 - x1 by twice the negated
+                    //limit, but the zone is empty first
+                    let mut dbm:$type = DBM::init(3);
+                    DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(limit()));
+                    DBM::restrict(&mut dbm, 0, 2, Bound::LessThanEqual(-limit()));
+                    assert_eq!(DBM::try_restrict(&mut dbm, 2, 1, Bound::LessThanEqual(-limit())), Ok(()));
+                    assert_eq!(DBM::is_empty(&dbm), true);
+                }
+
+                #[test]
+                fn test_rebuild_near_the_limit() {
+                    //rebuilt in the order of the matrix, x1 - x3 <= limit - 2 and x2 - x1 <= limit - 2 would bound x2 - x3
+                    //by almost twice the limit before x2 - x3 <= 0 is restricted
+                    let mut dbm:$type = DBM::init(4);
+                    DBM::restrict(&mut dbm, 2, 3, Bound::LessThanEqual(0));
+                    DBM::restrict(&mut dbm, 1, 3, Bound::LessThanEqual(limit() - 2));
+                    DBM::restrict(&mut dbm, 2, 1, Bound::LessThanEqual(limit() - 2));
+                    let rebuilt:$type = DBM::from_matrix(DBM::to_matrix(&dbm));
+                    assert_eq!(DBM::to_matrix(&rebuilt), DBM::to_matrix(&dbm));
+                    let mut hull = dbm.clone();
+                    let mut bounded = dbm.clone();
+                    DBM::restrict(&mut bounded, 1, 0, Bound::LessThanEqual(1));
+                    DBM::convex_hull(&mut hull, &bounded);
+                    assert_eq!(DBM::to_matrix(&hull), DBM::to_matrix(&dbm));
+                    let mut extrapolated = dbm.clone();
+                    DBM::extrapolate_max_bounds(&mut extrapolated, &[limit(); 4]);
+                    assert_eq!(DBM::to_matrix(&extrapolated), DBM::to_matrix(&dbm));
+                    let mut resized = dbm.clone();
+                    let clock = DBM::add_clock(&mut resized);
+                    DBM::remove_clock(&mut resized, clock);
+                    assert_eq!(DBM::to_matrix(&resized), DBM::to_matrix(&dbm));
+                }
             }
         )*
     };
@@ -279,11 +1864,149 @@ macro_rules! generate_tests { //Eli Bendersky came up with this approach, and I
 
 #[cfg(test)]
 mod tests {
-    use crate::{DBM, RDBM, RDBM_V1, RDBM_BITVEC, UDBM};
+    use crate::{minimal_constraints, Bound, Constraint, DbmError, Rational, Relation, DBM, RDBM, RDBM_V1, RDBM_BITVEC, UDBM, UDBM_I64, PARDIBAAL, REFERENCE};
+
+    //an integer valuation, indexed like the argument of `contains_point`
+    fn points(values: &[i64]) -> Vec<Rational> {
+        return values.iter().map(|&value| Rational::from_integer(value)).collect();
+    }
+
+    #[test]
+    fn test_bound_order() {
+        assert_eq!(Bound::LessThan(3) < Bound::LessThanEqual(3), true);
+        assert_eq!(Bound::LessThanEqual(3) < Bound::LessThan(4), true);
+        assert_eq!(Bound::LessThanEqual(i32::MAX) < Bound::Infinity, true);
+        assert_eq!(std::cmp::max(Bound::LessThan(-2), Bound::LessThanEqual(-2)), Bound::LessThanEqual(-2));
+    }
+
+    #[test]
+    fn test_bound_addition() {
+        assert_eq!(Bound::LessThanEqual(3) + Bound::LessThanEqual(-5), Bound::LessThanEqual(-2));
+        assert_eq!(Bound::LessThan(3) + Bound::LessThanEqual(-5), Bound::LessThan(-2));
+        assert_eq!(Bound::LessThanEqual(3) + Bound::LessThan(-5), Bound::LessThan(-2));
+        assert_eq!(Bound::LessThan(3) + Bound::Infinity, Bound::Infinity);
+        assert_eq!(Bound::<i32>::Infinity + Bound::Infinity, Bound::Infinity);
+    }
+
+    #[test]
+    fn test_bound_negation() {
+        assert_eq!(-Bound::LessThan(3), Bound::LessThanEqual(-3));
+        assert_eq!(-Bound::LessThanEqual(3), Bound::LessThan(-3));
+        assert_eq!(-(-Bound::LessThan(3)), Bound::LessThan(3));
+        //a bound and its negation never hold together: their sum is a negative cycle
+        assert_eq!(Bound::LessThanEqual(3) + -Bound::LessThanEqual(3) < Bound::LessThanEqual(0), true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bound_negation_of_infinity_panics() {
+        let _ = -Bound::<i32>::Infinity;
+    }
+
+    #[test]
+    fn test_reference_overflow() {
+        //constants whose negation or sum does not fit in i64 are rejected before any arithmetic
+        let mut dbm: REFERENCE = DBM::init(3);
+        let expected = dbm.clone();
+        assert_eq!(DBM::try_restrict(&mut dbm, 1, 0, Bound::LessThanEqual(i64::MAX)), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_assign(&mut dbm, 1, i64::MIN), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_shift(&mut dbm, 1, i64::MIN), Err(DbmError::Overflow));
+        assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal);
+    }
+
+    #[test]
+    fn test_udbm_i64_large_constants() {
+        let mut dbm: UDBM_I64 = DBM::init(3);
+        DBM::restrict(&mut dbm, 1, 0, Bound::LessThanEqual(300_000_000));
+        DBM::restrict(&mut dbm, 0, 2, Bound::LessThan(-290_000_000));
+        assert_eq!(DBM::get_bound(&dbm, 1, 0), Bound::LessThanEqual(300_000_000));
+        assert_eq!(DBM::get_bound(&dbm, 0, 2), Bound::LessThan(-290_000_000));
+        assert_eq!(DBM::get_bound(&dbm, 1, 2), Bound::LessThan(10_000_000));
+    }
+
+    #[test]
+    fn test_udbm_i64_overflow() {
+        //constants beyond the udbm limit are rejected rather than moving the zone out of udbm
+        let mut dbm: UDBM_I64 = DBM::init(3);
+        let expected = dbm.clone();
+        assert_eq!(DBM::try_restrict(&mut dbm, 1, 0, Bound::LessThanEqual(1 << 31)), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_restrict(&mut dbm, 0, 1, Bound::LessThan(i64::MIN)), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_is_satisfied(&dbm, 1, 0, Bound::LessThan(i64::MAX)), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_assign(&mut dbm, 1, 1 << 40), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_shift(&mut dbm, 1, -(1 << 40)), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_update(&mut dbm, 1, 2, 1 << 40), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_extrapolate_max_bounds(&mut dbm, &[0, 10, 1 << 40]), Err(DbmError::Overflow));
+        assert_eq!(DBM::try_future_within(&mut dbm, &[Constraint::new(1, 0, Bound::LessThan(1 << 40))]), Err(DbmError::Overflow));
+        assert_eq!(DBM::relation(&dbm, &expected), Relation::Equal); //rejected constants never reach udbm
+        assert_eq!(DBM::try_restrict(&mut dbm, 1, 0, Bound::Infinity), Ok(()));
+    }
+
+    #[test]
+    fn test_rdbm_v1_dimension_limit() {
+        let dbm: RDBM_V1<i32> = DBM::init(256); //255 clocks and the reference clock
+        assert_eq!(DBM::dimension(&dbm), 256);
+        assert_eq!(DBM::is_satisfied(&dbm, 255, 0, Bound::LessThanEqual(10)), true);
+        assert_eq!(<RDBM_V1<i32> as DBM<i32>>::try_init(257).err(), Some(DbmError::DimensionTooLarge { dim: 257, max: 256 }));
+        assert_eq!(<RDBM_V1<i32> as DBM<i32>>::try_zero(300).err(), Some(DbmError::DimensionTooLarge { dim: 300, max: 256 }));
+    }
+
+    #[test]
+    fn test_backend_error_causes() {
+        //a backend error is mapped onto its cause in the call, and only kept as a backend error if there is none
+        let from_backend = |clocks: &[usize], dim, empty| crate::error::from_backend(clocks, dim, empty)("error");
+        assert_eq!(from_backend(&[1, 4], 3, true), DbmError::ClockOutOfRange { clock: 4, dim: 3 });
+        assert_eq!(from_backend(&[1, 2], 3, true), DbmError::InconsistentZone);
+        assert_eq!(from_backend(&[], 3, false), DbmError::Backend(format!("{:?}", "error")));
+    }
+
+    //A zone and its extrapolation are bisimilar if every guard within the maximal constants is satisfiable in both or in
+    //neither, also after letting time pass. Upper bound guards x <= c are checked against `upper`, lower bound guards
+    //x >= c against `lower`.
+    fn assert_guards_agree<T, D>(dbm: &D, extrapolated: &D, lower: &[T], upper: &[T])
+    where
+        T: Copy + Ord + From<i8> + std::ops::Neg<Output = T>,
+        D: DBM<T> + Clone,
+    {
+        let constants_up_to = |max: T| (0..=i8::MAX).map(T::from).take_while(move |constant| *constant <= max);
+        let mut delayed = dbm.clone();
+        let mut extrapolated_delayed = extrapolated.clone();
+        DBM::future(&mut delayed);
+        DBM::future(&mut extrapolated_delayed);
+        for (x, y) in [(dbm, extrapolated), (&delayed, &extrapolated_delayed)].iter() {
+            for clock in 1..upper.len() {
+                for bound in [Bound::LessThan as fn(T) -> Bound<T>, Bound::LessThanEqual].iter() {
+                    for constant in constants_up_to(upper[clock]) {
+                        assert_eq!(D::is_satisfied(*x, clock, 0, bound(constant)), D::is_satisfied(*y, clock, 0, bound(constant)));
+                    }
+                    for constant in constants_up_to(lower[clock]) {
+                        assert_eq!(D::is_satisfied(*x, 0, clock, bound(-constant)), D::is_satisfied(*y, 0, clock, bound(-constant)));
+                    }
+                }
+            }
+        }
+    }
+
     generate_tests! {
         udbm: UDBM,
         rdbm: RDBM<i8>,
         rdbm_v1: RDBM_V1<i8>,
         rdbm_bitvec: RDBM_BITVEC<i8>,
+        pardibaal: PARDIBAAL,
+        reference: REFERENCE,
+    }
+
+    //every backend against its own limit, with rdbm at i8 where the limit is smallest
+    mod overflow {
+        use super::*;
+        use crate::overflow::third_of_range;
+
+        generate_overflow_tests! {
+            udbm: UDBM, i32, crate::dbms::udbm::MAX_CONSTANT;
+            rdbm: RDBM<i8>, i8, third_of_range::<i8>();
+            rdbm_v1: RDBM_V1<i8>, i8, third_of_range::<i8>();
+            rdbm_bitvec: RDBM_BITVEC<i8>, i8, third_of_range::<i8>();
+            pardibaal: PARDIBAAL, i32, third_of_range::<i32>();
+            reference: REFERENCE, i64, third_of_range::<i64>();
+        }
     }
 }
@@ -0,0 +1,192 @@
+use crate::{Bound, DbmError, DBM};
+use num::Bounded;
+use std::convert::TryFrom;
+
+//bounds are recomputed in 128 bits, where sums of 64-bit constants cannot overflow
+type Wide = Bound<i128>;
+
+fn wide<T: Clone + Into<i64>>(constant: &T) -> i128 {
+    return i128::from(constant.clone().into());
+}
+
+fn widen<T: Clone + Into<i64>>(bound: &Bound<T>) -> Wide {
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(wide(constant)),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(wide(constant)),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+/// The largest constant a backend accepts when it stores constants as `T` and may add up to three
+/// of them before comparing, as the incremental closure after a restriction does. Every sum of
+/// three constants within this limit is then finite and representable, even with the largest
+/// value of `T` reserved for infinity.
+pub(crate) fn third_of_range<T: Bounded + Into<i64>>() -> i64 {
+    return (T::max_value().into() - 1) / 3;
+}
+
+pub(crate) fn check_constant(constant: i128, limit: i64) -> Result<(), DbmError> {
+    if constant.abs() > i128::from(limit) {
+        return Err(DbmError::Overflow);
+    }
+    return Ok(());
+}
+
+fn check_wide(bound: &Wide, limit: i64) -> Result<(), DbmError> {
+    return match bound.constant() {
+        Some(&constant) => check_constant(constant, limit),
+        None => Ok(()),
+    };
+}
+
+pub(crate) fn check_bound<T: Clone + Into<i64>>(bound: &Bound<T>, limit: i64) -> Result<(), DbmError> {
+    return check_wide(&widen(bound), limit);
+}
+
+/// Checks that restricting `x_i - x_j` to `bound` keeps every bound of `dbm` within `limit`,
+/// without changing `dbm`. The bounds of a closed DBM are within the limit, so a restriction
+/// that tightens nothing passes, and otherwise only the sums `m[a][i] + bound + m[j][c]` that
+/// the incremental closure tightens with need checking. A sum below the negated limit always
+/// tightens its bound, and one above the limit only tightens an infinite bound. The pairs are only
+/// visited when the extremes of row `i` and column `j` can cross the limit. Restrictions that
+/// empty the zone never fail, since an empty zone has no bounds to keep.
+pub(crate) fn check_restrict<T, D>(dbm: &D, i: usize, j: usize, bound: &Bound<T>, limit: i64) -> Result<(), DbmError>
+where
+    T: Clone + Into<i64>,
+    D: DBM<T>,
+{
+    check_bound(bound, limit)?;
+    let bound = widen(bound);
+    if bound >= widen(&D::try_get_bound(dbm, i, j)?) || D::is_empty(dbm) {
+        return Ok(());
+    }
+    if bound + widen(&D::try_get_bound(dbm, j, i)?) < Bound::LessThanEqual(0) {
+        return Ok(());
+    }
+    let dim = D::dimension(dbm);
+    let constant = *bound.constant().expect("a bound that tightens is finite");
+    //the extremes of the row and column bound every sum, so the pairs only need checking when they can cross the limit
+    let extreme = |pick: fn(i128, i128) -> i128| {
+        let into = (0..dim).filter_map(|a| widen(&D::get_bound(dbm, a, i)).constant().copied()).reduce(pick)?;
+        let from = (0..dim).filter_map(|c| widen(&D::get_bound(dbm, j, c)).constant().copied()).reduce(pick)?;
+        Some(into + constant + from)
+    };
+    let below = extreme(i128::min).is_some_and(|lowest| lowest < -i128::from(limit));
+    let above = extreme(i128::max).is_some_and(|highest| highest > i128::from(limit));
+    if !below && !above {
+        return Ok(());
+    }
+    let into_i: Vec<Wide> = (0..dim).map(|a| widen(&D::get_bound(dbm, a, i))).collect();
+    let from_j: Vec<Wide> = (0..dim).map(|c| widen(&D::get_bound(dbm, j, c))).collect();
+    for (a, &into) in into_i.iter().enumerate() {
+        for (c, &from) in from_j.iter().enumerate() {
+            let tightens_beyond_limit = match (into + bound + from).constant() {
+                Some(&constant) if constant < -i128::from(limit) => true,
+                Some(&constant) if constant > i128::from(limit) => D::get_bound(dbm, a, c).is_infinity(),
+                _ => false,
+            };
+            if tightens_beyond_limit {
+                return Err(DbmError::Overflow);
+            }
+        }
+    }
+    return Ok(());
+}
+
+/// Checks that every bound of `dbm` is within `limit`, for operations that are only checked after
+/// they are done.
+pub(crate) fn check_all<T, D>(dbm: &D, limit: i64) -> Result<(), DbmError>
+where
+    T: Clone + Into<i64>,
+    D: DBM<T>,
+{
+    if D::is_empty(dbm) {
+        return Ok(());
+    }
+    let dim = D::dimension(dbm);
+    for i in 0..dim {
+        for j in 0..dim {
+            check_bound(&D::get_bound(dbm, i, j), limit)?;
+        }
+    }
+    return Ok(());
+}
+
+//bounds within the limit fit in 64 bits, and in the constant type of the backend whose limit it is
+fn narrow<T: TryFrom<i64>>(bound: &Wide) -> Bound<T> {
+    let narrow = |constant: i128| match i64::try_from(constant).ok().and_then(|constant| T::try_from(constant).ok()) {
+        Some(constant) => constant,
+        None => unreachable!("a bound within the limit fits the constant type"),
+    };
+    return match *bound {
+        Bound::LessThan(constant) => Bound::LessThan(narrow(constant)),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(narrow(constant)),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+/// Closes `matrix` in 128 bits and checks that its bounds, and those of the closed matrix, are
+/// within `limit`. Bounds above `<= 0` on `x_0 - x_j` are tightened to it, since clocks are never
+/// negative, and the diagonal is ignored. Returns the closed matrix, or `None` if its zone is
+/// empty, which has no bounds to keep.
+pub(crate) fn close_matrix<T>(matrix: &[Vec<Bound<T>>], limit: i64) -> Result<Option<Vec<Vec<Bound<T>>>>, DbmError>
+where
+    T: Clone + Into<i64> + TryFrom<i64>,
+{
+    let dim = matrix.len();
+    let mut closed: Vec<Vec<Wide>> = Vec::with_capacity(dim);
+    for (i, row) in matrix.iter().enumerate() {
+        let mut wide_row = Vec::with_capacity(dim);
+        for (j, bound) in row.iter().enumerate() {
+            if i == j {
+                wide_row.push(Bound::LessThanEqual(0));
+                continue;
+            }
+            check_bound(bound, limit)?;
+            wide_row.push(match i {
+                0 => std::cmp::min(widen(bound), Bound::LessThanEqual(0)),
+                _ => widen(bound),
+            });
+        }
+        closed.push(wide_row);
+    }
+    //a negative cycle through the pivot is caught before the next pivot, as repeating it would grow the bounds without end
+    for k in 0..dim {
+        for i in 0..dim {
+            for j in 0..dim {
+                let through_k = closed[i][k] + closed[k][j];
+                if through_k < closed[i][j] {
+                    closed[i][j] = through_k;
+                }
+            }
+        }
+        if (0..dim).any(|i| closed[i][i] < Bound::LessThanEqual(0)) {
+            return Ok(None);
+        }
+    }
+    for bound in closed.iter().flatten() {
+        check_wide(bound, limit)?;
+    }
+    return Ok(Some(closed.iter().map(|row| row.iter().map(narrow).collect()).collect()));
+}
+
+/// Checks that `x_clock_to := x_clock_from + constant` keeps every bound of `dbm` within `limit`.
+/// The bounds of the updated clock are those of `clock_from` moved by `constant`, and the rest
+/// of the zone is unchanged, so only those need checking. Assigning is updating from the
+/// reference clock, and shifting is updating a clock from itself.
+pub(crate) fn check_update<T, D>(dbm: &D, clock_to: usize, clock_from: usize, constant: &T, limit: i64) -> Result<(), DbmError>
+where
+    T: Clone + Into<i64>,
+    D: DBM<T>,
+{
+    let constant = wide(constant);
+    check_constant(constant, limit)?;
+    if D::is_empty(dbm) {
+        return Ok(());
+    }
+    for k in (0..D::dimension(dbm)).filter(|&k| k != clock_to) {
+        check_wide(&(widen(&D::try_get_bound(dbm, clock_from, k)?) + Bound::LessThanEqual(constant)), limit)?;
+        check_wide(&(widen(&D::try_get_bound(dbm, k, clock_from)?) + Bound::LessThanEqual(-constant)), limit)?;
+    }
+    return Ok(());
+}
@@ -0,0 +1,69 @@
+use crate::Bound;
+use num::rational::Ratio;
+
+/// The value of a clock in a concrete valuation.
+pub type Rational = Ratio<i64>;
+
+fn to_rational<T: Clone + Into<i64>>(constant: &T) -> Rational {
+    return Rational::from_integer(constant.clone().into());
+}
+
+fn satisfies<T: Clone + Into<i64>>(bound: &Bound<T>, difference: Rational) -> bool {
+    return match bound {
+        Bound::LessThan(constant) => difference < to_rational(constant),
+        Bound::LessThanEqual(constant) => difference <= to_rational(constant),
+        Bound::Infinity => true,
+    };
+}
+
+/// Whether `x_i - x_j` satisfies `matrix[i][j]` for every pair of clocks, with `point[i]` as the
+/// value of `x_i`.
+pub(crate) fn contains_point<T: Clone + Into<i64>>(matrix: &[Vec<Bound<T>>], point: &[Rational]) -> bool {
+    return matrix.iter().enumerate().all(|(i, row)| {
+        row.iter()
+            .enumerate()
+            .all(|(j, bound)| satisfies(bound, point[i] - point[j]))
+    });
+}
+
+/// Picks a valuation in the zone of a closed, non-empty matrix, one clock at a time. Closure
+/// guarantees that the values chosen for earlier clocks always leave room for the next one.
+/// Integer values are preferred, and only strict bounds on both sides can force a fraction.
+pub(crate) fn sample_point<T: Clone + Into<i64>>(matrix: &[Vec<Bound<T>>]) -> Vec<Rational> {
+    let mut point = vec![Rational::from_integer(0)];
+    for (k, row) in matrix.iter().enumerate().skip(1) {
+        //the tightest bounds on x_k as (value, is_strict), where an upper bound of None is unbounded
+        let mut lower = (Rational::from_integer(0), false);
+        let mut upper: Option<(Rational, bool)> = None;
+        for (j, &value) in point.iter().enumerate() {
+            //x_j - x_k < c gives x_k > x_j - c
+            if let Some(constant) = matrix[j][k].constant() {
+                let candidate = (value - to_rational(constant), matrix[j][k].is_strict());
+                if candidate.0 > lower.0 || (candidate.0 == lower.0 && candidate.1) {
+                    lower = candidate;
+                }
+            }
+            //x_k - x_j < c gives x_k < x_j + c
+            if let Some(constant) = row[j].constant() {
+                let candidate = (value + to_rational(constant), row[j].is_strict());
+                upper = match upper {
+                    Some(bound) if bound.0 < candidate.0 || (bound.0 == candidate.0 && bound.1) => Some(bound),
+                    _ => Some(candidate),
+                };
+            }
+        }
+        let below_upper = |value: Rational| match upper {
+            Some((bound, true)) => value < bound,
+            Some((bound, false)) => value <= bound,
+            None => true,
+        };
+        let next_integer = lower.0.floor() + 1;
+        point.push(match (lower, upper) {
+            ((value, false), _) => value,
+            _ if below_upper(next_integer) => next_integer,
+            ((value, true), Some((bound, _))) => (value + bound) / 2,
+            ((value, true), None) => value + 1,
+        });
+    }
+    return point;
+}
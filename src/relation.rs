@@ -0,0 +1,31 @@
+/// How two zones relate to each other, as computed by `DBM::relation`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Relation {
+    Equal,
+    /// The left-hand zone is strictly included in the right-hand zone.
+    Subset,
+    /// The left-hand zone strictly includes the right-hand zone.
+    Superset,
+    Different,
+}
+
+impl Relation {
+    pub(crate) fn from_inclusions(is_subset: bool, is_superset: bool) -> Relation {
+        return match (is_subset, is_superset) {
+            (true, true) => Relation::Equal,
+            (true, false) => Relation::Subset,
+            (false, true) => Relation::Superset,
+            (false, false) => Relation::Different,
+        };
+    }
+
+    /// The relation of two zones of which at least one is empty, or `None` if neither is. Bounds
+    /// say nothing about an empty zone, so emptiness is settled before any bounds are compared: an
+    /// empty zone is included in every zone, and two empty zones are equal.
+    pub(crate) fn of_empty(lhs_is_empty: bool, rhs_is_empty: bool) -> Option<Relation> {
+        if !lhs_is_empty && !rhs_is_empty {
+            return None;
+        }
+        return Some(Relation::from_inclusions(lhs_is_empty, rhs_is_empty));
+    }
+}
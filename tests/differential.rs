@@ -0,0 +1,367 @@
+//Differential testing of the backends: random operation sequences are applied to every backend in lockstep, and after
+//every step the zones are compared with those of the reference backend, through their exported bounds and through
+//mutual inclusion. A disagreement is shrunk to a minimal failing sequence before it is reported.
+//
+//The run is deterministic. Set DBM_DIFFERENTIAL_SEED and DBM_DIFFERENTIAL_CASES to explore further.
+
+use dbm_baenk::{Bound, DbmError, Relation, DBM, PARDIBAAL, RDBM, RDBM_BITVEC, RDBM_V1, REFERENCE, UDBM};
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+//xorshift64*, so that a failure reproduces from its seed without depending on rand
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        return Rng(seed.max(1));
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        return self.0.wrapping_mul(0x2545_f491_4f6c_dd1d);
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        return (self.next() % bound as u64) as usize;
+    }
+
+    fn between(&mut self, low: i8, high: i8) -> i8 {
+        return (low as i16 + self.below((high as i16 - low as i16) as usize + 1) as i16) as i8;
+    }
+
+    fn coin(&mut self) -> bool {
+        return self.next() & 1 == 1;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Operation {
+    Future,
+    Past,
+    Close,
+    Restrict(usize, usize, Bound<i8>),
+    Free(usize),
+    Assign(usize, i8),
+    Copy(usize, usize),
+    Shift(usize, i8),
+    Update(usize, usize, i8),
+}
+
+#[derive(Clone, Debug)]
+struct Case {
+    dim: usize,
+    zero: bool,
+    operations: Vec<Operation>,
+}
+
+fn random_operation(rng: &mut Rng, dim: usize) -> Operation {
+    let clock = |rng: &mut Rng| 1 + rng.below(dim - 1);
+    return match rng.below(10) {
+        0 => Operation::Future,
+        1 => Operation::Past,
+        2 => Operation::Close,
+        3 | 4 => {
+            let i = rng.below(dim);
+            let j = (i + 1 + rng.below(dim - 1)) % dim;
+            let constant = rng.between(-10, 10);
+            let bound = match rng.below(5) {
+                0 => Bound::Infinity,
+                1 | 2 => Bound::LessThan(constant),
+                _ => Bound::LessThanEqual(constant),
+            };
+            Operation::Restrict(i, j, bound)
+        }
+        5 => Operation::Free(clock(rng)),
+        6 => Operation::Assign(clock(rng), rng.between(0, 10)),
+        7 => Operation::Copy(clock(rng), clock(rng)),
+        //shifts stay non-negative, so clock values never need to be clamped at zero
+        8 => Operation::Shift(clock(rng), rng.between(0, 5)),
+        _ => Operation::Update(clock(rng), clock(rng), rng.between(0, 5)),
+    };
+}
+
+fn random_case(rng: &mut Rng) -> Case {
+    let dim = 2 + rng.below(5);
+    let length = 1 + rng.below(30);
+    return Case {
+        dim,
+        zero: rng.coin(),
+        operations: (0..length).map(|_| random_operation(rng, dim)).collect(),
+    };
+}
+
+//the DBM trait is not object safe, so every backend is driven through this instead
+trait Backend {
+    fn name(&self) -> &'static str;
+    fn apply(&mut self, operation: Operation) -> Result<(), DbmError>;
+    fn is_empty(&self) -> bool;
+    fn bounds(&self) -> Vec<Vec<Bound<i64>>>;
+    //rebuilds `bounds` in this backend and returns whether it includes, and is included in, the zone
+    fn inclusions(&self, bounds: &[Vec<Bound<i64>>]) -> (bool, bool);
+}
+
+struct Lockstep<T, D> {
+    name: &'static str,
+    dbm: D,
+    constant: PhantomData<T>,
+}
+
+fn convert<T, U: TryFrom<T>>(bound: Bound<T>) -> Bound<U> {
+    let convert = |constant| U::try_from(constant).ok().expect("differential constants fit every backend");
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(convert(constant)),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(convert(constant)),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+impl<T, D> Backend for Lockstep<T, D>
+where
+    T: From<i8> + TryFrom<i64> + Into<i64> + Clone,
+    i64: From<T>,
+    D: DBM<T>,
+{
+    fn name(&self) -> &'static str {
+        return self.name;
+    }
+
+    fn apply(&mut self, operation: Operation) -> Result<(), DbmError> {
+        let dbm = &mut self.dbm;
+        return match operation {
+            Operation::Future => D::try_future(dbm),
+            Operation::Past => D::try_past(dbm),
+            Operation::Close => D::try_close(dbm).map(|_| ()),
+            Operation::Restrict(i, j, bound) => D::try_restrict(dbm, i, j, convert(bound)),
+            Operation::Free(clock) => D::try_free(dbm, clock),
+            Operation::Assign(clock, value) => D::try_assign(dbm, clock, value.into()),
+            Operation::Copy(clock_to, clock_from) => D::try_copy(dbm, clock_to, clock_from),
+            Operation::Shift(clock, constant) => D::try_shift(dbm, clock, constant.into()),
+            Operation::Update(clock_to, clock_from, constant) => D::try_update(dbm, clock_to, clock_from, constant.into()),
+        };
+    }
+
+    fn is_empty(&self) -> bool {
+        return D::is_empty(&self.dbm);
+    }
+
+    fn bounds(&self) -> Vec<Vec<Bound<i64>>> {
+        return D::to_matrix(&self.dbm)
+            .into_iter()
+            .map(|row| row.into_iter().map(convert).collect())
+            .collect();
+    }
+
+    fn inclusions(&self, bounds: &[Vec<Bound<i64>>]) -> (bool, bool) {
+        let matrix = bounds.iter().map(|row| row.iter().cloned().map(convert).collect()).collect();
+        let other = D::from_matrix(matrix);
+        return (D::is_included_in(&self.dbm, &other), D::is_included_in(&other, &self.dbm));
+    }
+}
+
+fn lockstep<T, D>(name: &'static str, case: &Case) -> Box<dyn Backend>
+where
+    T: From<i8> + TryFrom<i64> + Into<i64> + Clone + 'static,
+    i64: From<T>,
+    D: DBM<T> + 'static,
+{
+    let dbm = match case.zero {
+        true => D::zero(case.dim),
+        false => D::init(case.dim),
+    };
+    return Box::new(Lockstep { name, dbm, constant: PhantomData });
+}
+
+//the reference backend comes first, since every other backend is compared with the first one
+fn all_backends(case: &Case) -> Vec<Box<dyn Backend>> {
+    return vec![
+        lockstep::<_, REFERENCE>("reference", case),
+        lockstep::<_, UDBM>("udbm", case),
+        lockstep::<_, RDBM<i32>>("rdbm", case),
+        lockstep::<_, RDBM_V1<i32>>("rdbm_v1", case),
+        lockstep::<_, RDBM_BITVEC<i32>>("rdbm_bitvec", case),
+        lockstep::<_, PARDIBAAL>("pardibaal", case),
+    ];
+}
+
+fn compare(backends: &[Box<dyn Backend>], results: &[Result<(), DbmError>]) -> Result<(), String> {
+    let (reference, reference_result) = (&backends[0], &results[0]);
+    for (backend, result) in backends.iter().zip(results.iter()).skip(1) {
+        if result != reference_result {
+            return Err(format!("{} returned {:?}, {} returned {:?}", reference.name(), reference_result, backend.name(), result));
+        }
+        if backend.is_empty() != reference.is_empty() {
+            return Err(format!("{} and {} disagree on emptiness", reference.name(), backend.name()));
+        }
+    }
+    //empty zones have no canonical bounds to compare
+    if reference.is_empty() {
+        return Ok(());
+    }
+    let bounds: Vec<_> = backends.iter().map(|backend| backend.bounds()).collect();
+    for (backend, backend_bounds) in backends.iter().zip(bounds.iter()).skip(1) {
+        if *backend_bounds != bounds[0] {
+            return Err(format!("{} exported {:?}, {} exported {:?}", reference.name(), bounds[0], backend.name(), backend_bounds));
+        }
+    }
+    for backend in backends.iter() {
+        for (other, other_bounds) in backends.iter().zip(bounds.iter()) {
+            if backend.inclusions(other_bounds) != (true, true) {
+                return Err(format!("the zone of {} rebuilt in {} is not equal to it", other.name(), backend.name()));
+            }
+        }
+    }
+    return Ok(());
+}
+
+//runs the case and describes the first step after which the backends disagree
+fn run(case: &Case, backends: &dyn Fn(&Case) -> Vec<Box<dyn Backend>>) -> Result<(), String> {
+    let mut backends = backends(case);
+    compare(&backends, &vec![Ok(()); backends.len()]).map_err(|error| format!("before any operation: {}", error))?;
+    for (step, operation) in case.operations.iter().enumerate() {
+        let results: Vec<_> = backends.iter_mut().map(|backend| backend.apply(*operation)).collect();
+        compare(&backends, &results).map_err(|error| format!("after step {} ({:?}): {}", step, operation, error))?;
+        //backends make no promises about the bounds of empty zones, so there is nothing left to compare
+        if backends[0].is_empty() {
+            break;
+        }
+    }
+    return Ok(());
+}
+
+//drops operations one at a time for as long as the case keeps failing
+fn shrink(mut case: Case, backends: &dyn Fn(&Case) -> Vec<Box<dyn Backend>>) -> Case {
+    loop {
+        let mut shrunk = false;
+        for index in (0..case.operations.len()).rev() {
+            let mut candidate = case.clone();
+            candidate.operations.remove(index);
+            if run(&candidate, backends).is_err() {
+                case = candidate;
+                shrunk = true;
+            }
+        }
+        if !shrunk {
+            return case;
+        }
+    }
+}
+
+fn environment(name: &str, default: u64) -> u64 {
+    return std::env::var(name).ok().and_then(|value| value.parse().ok()).unwrap_or(default);
+}
+
+//the first failing case, already shrunk
+fn find_failure(seed: u64, cases: u64, backends: &dyn Fn(&Case) -> Vec<Box<dyn Backend>>) -> Option<(Case, String)> {
+    let mut rng = Rng::new(seed);
+    for _ in 0..cases {
+        let case = random_case(&mut rng);
+        if run(&case, backends).is_err() {
+            let minimal = shrink(case, backends);
+            let error = run(&minimal, backends).unwrap_err();
+            return Some((minimal, error));
+        }
+    }
+    return None;
+}
+
+#[test]
+fn test_backends_agree_on_random_sequences() {
+    let seed = environment("DBM_DIFFERENTIAL_SEED", 0x5eed_dbb5);
+    let cases = environment("DBM_DIFFERENTIAL_CASES", 500);
+    if let Some((minimal, error)) = find_failure(seed, cases, &all_backends) {
+        panic!("backends disagree with seed {}: {}\nminimal failing case: {:#?}", seed, error, minimal);
+    }
+}
+
+//the zones the reference backend passes through on random cases, grouped by dimension, with two empty zones of every
+//dimension whose bounds differ
+fn reference_zones(rng: &mut Rng, per_dimension: usize) -> Vec<Vec<REFERENCE>> {
+    let mut zones: Vec<Vec<REFERENCE>> = vec![Vec::new(); 7];
+    for (dim, group) in zones.iter_mut().enumerate().skip(2) {
+        let mut empty: REFERENCE = DBM::init(dim);
+        DBM::restrict(&mut empty, 1, 0, Bound::LessThan(0));
+        let mut other_empty: REFERENCE = DBM::init(dim);
+        DBM::restrict(&mut other_empty, 1, 0, Bound::LessThanEqual(3));
+        DBM::restrict(&mut other_empty, 0, 1, Bound::LessThan(-5));
+        group.push(empty);
+        group.push(other_empty);
+    }
+    while zones.iter().skip(2).any(|group| group.len() < per_dimension) {
+        let case = random_case(rng);
+        let dbm: REFERENCE = match case.zero {
+            true => DBM::zero(case.dim),
+            false => DBM::init(case.dim),
+        };
+        let mut oracle = Lockstep { name: "reference", dbm, constant: PhantomData::<i64> };
+        for operation in case.operations {
+            let _ = oracle.apply(operation);
+            if zones[case.dim].len() < per_dimension {
+                zones[case.dim].push(oracle.dbm.clone());
+            }
+            if oracle.is_empty() {
+                break;
+            }
+        }
+    }
+    return zones;
+}
+
+//every backend is compared with the reference backend, so it has to agree with itself first: its relation must be the
+//pair of inclusions it reports, also for empty zones
+#[test]
+fn test_reference_relation_matches_inclusions() {
+    let mut rng = Rng::new(environment("DBM_DIFFERENTIAL_SEED", 0x5eed_dbb5));
+    for group in reference_zones(&mut rng, 40) {
+        for lhs in group.iter() {
+            for rhs in group.iter() {
+                let expected = match (DBM::is_included_in(lhs, rhs), DBM::is_included_in(rhs, lhs)) {
+                    (true, true) => Relation::Equal,
+                    (true, false) => Relation::Subset,
+                    (false, true) => Relation::Superset,
+                    (false, false) => Relation::Different,
+                };
+                assert_eq!(DBM::relation(lhs, rhs), expected, "relation of {:?} to {:?}", lhs, rhs);
+            }
+        }
+    }
+}
+
+//a backend that forgets to free clocks, to check that disagreements are found and shrunk
+struct IgnoresFree(Box<dyn Backend>);
+
+impl Backend for IgnoresFree {
+    fn name(&self) -> &'static str {
+        return "ignores_free";
+    }
+
+    fn apply(&mut self, operation: Operation) -> Result<(), DbmError> {
+        return match operation {
+            Operation::Free(_) => Ok(()),
+            _ => self.0.apply(operation),
+        };
+    }
+
+    fn is_empty(&self) -> bool {
+        return self.0.is_empty();
+    }
+
+    fn bounds(&self) -> Vec<Vec<Bound<i64>>> {
+        return self.0.bounds();
+    }
+
+    fn inclusions(&self, bounds: &[Vec<Bound<i64>>]) -> (bool, bool) {
+        return self.0.inclusions(bounds);
+    }
+}
+
+#[test]
+fn test_disagreement_is_shrunk() {
+    let backends = |case: &Case| vec![lockstep::<_, UDBM>("udbm", case), Box::new(IgnoresFree(lockstep::<_, UDBM>("udbm", case))) as Box<dyn Backend>];
+    let (minimal, error) = find_failure(1, 500, &backends).expect("a backend that ignores free should be caught");
+    //freeing a clock of the zero zone fails on its own, anything else needs one operation to pin the clock first
+    assert!(minimal.operations.len() <= 2, "not shrunk: {:?}", minimal);
+    assert!(matches!(minimal.operations.last(), Some(Operation::Free(_))), "{:?}", minimal);
+    assert!(error.contains("ignores_free"), "{}", error);
+}
@@ -0,0 +1,281 @@
+//Algebraic laws of the DBM operations, checked with proptest on random zones for every backend. The zones are built
+//by random operation sequences from init or zero, so they cover far more shapes than the fixed cases in the unit tests.
+//The seed is fixed, so every run checks the same cases and a failure can be reproduced by running the tests again.
+
+use dbm_baenk::{Bound, Relation, DBM, PARDIBAAL, RDBM, RDBM_BITVEC, RDBM_V1, REFERENCE, UDBM};
+use proptest::prelude::*;
+use proptest::test_runner::RngSeed;
+
+fn config() -> ProptestConfig {
+    return ProptestConfig { rng_seed: RngSeed::Fixed(0), ..ProptestConfig::default() };
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    Restrict(usize, usize, bool, i8),
+    Future,
+    Free(usize),
+    Assign(usize, i8),
+    Shift(usize, i8),
+}
+
+//clock indices are drawn large and reduced modulo the dimension when the step is applied
+fn step() -> impl Strategy<Value = Step> {
+    return prop_oneof![
+        3 => (0..8usize, 0..8usize, any::<bool>(), -10..=10i8).prop_map(|(i, j, strict, c)| Step::Restrict(i, j, strict, c)),
+        1 => Just(Step::Future),
+        1 => (0..8usize).prop_map(Step::Free),
+        1 => (0..8usize, 0..=10i8).prop_map(|(clock, value)| Step::Assign(clock, value)),
+        1 => (0..8usize, 0..=5i8).prop_map(|(clock, constant)| Step::Shift(clock, constant)),
+    ];
+}
+
+#[derive(Clone, Debug)]
+struct Zone {
+    dim: usize,
+    zero: bool,
+    steps: Vec<Step>,
+}
+
+fn zone_of(dim: usize) -> impl Strategy<Value = Zone> {
+    return (any::<bool>(), prop::collection::vec(step(), 0..12)).prop_map(move |(zero, steps)| Zone { dim, zero, steps });
+}
+
+fn zone() -> impl Strategy<Value = Zone> {
+    return (3..=6usize).prop_flat_map(zone_of);
+}
+
+//a constraint x_i - x_j (< or <=) c, where j is drawn as an offset from i so that the clocks always differ
+fn guard() -> impl Strategy<Value = (usize, usize, bool, i8)> {
+    return (0..8usize, 0..8usize, any::<bool>(), -10..=10i8);
+}
+
+fn bound<T: From<i8>>(strict: bool, constant: i8) -> Bound<T> {
+    return match strict {
+        true => Bound::LessThan(constant.into()),
+        false => Bound::LessThanEqual(constant.into()),
+    };
+}
+
+fn build<T: From<i8>, D: DBM<T>>(zone: &Zone) -> D {
+    let mut dbm = match zone.zero {
+        true => D::zero(zone.dim),
+        false => D::init(zone.dim),
+    };
+    //clocks other than the reference clock, for the operations that may not touch it
+    let clock = |clock: usize| 1 + clock % (zone.dim - 1);
+    //operations on empty zones are undefined in udbm, so building stops as soon as the zone is empty
+    for step in zone.steps.iter() {
+        if D::is_empty(&dbm) {
+            break;
+        }
+        match *step {
+            Step::Restrict(i, j, strict, c) if i % zone.dim != j % zone.dim => D::restrict(&mut dbm, i % zone.dim, j % zone.dim, bound(strict, c)),
+            Step::Restrict(..) => {}
+            Step::Future => D::future(&mut dbm),
+            Step::Free(k) => D::free(&mut dbm, clock(k)),
+            Step::Assign(k, value) => D::assign(&mut dbm, clock(k), value.into()),
+            Step::Shift(k, constant) => D::shift(&mut dbm, clock(k), constant.into()),
+        }
+    }
+    return dbm;
+}
+
+fn restrict_in_order<T: From<i8>, D: DBM<T> + Clone>(dbm: &D, dim: usize, guards: &[(usize, usize, bool, i8)]) -> D {
+    let mut dbm = dbm.clone();
+    for &(i, offset, strict, c) in guards.iter() {
+        if D::is_empty(&dbm) {
+            break;
+        }
+        let i = i % dim;
+        D::restrict(&mut dbm, i, (i + 1 + offset % (dim - 1)) % dim, bound(strict, c));
+    }
+    return dbm;
+}
+
+//a change that loosens or tightens a zone, to derive zones that are likely related by inclusion
+#[derive(Clone, Copy, Debug)]
+enum Change {
+    Future,
+    Free(usize),
+    Restrict(usize, usize, bool, i8),
+}
+
+fn change() -> impl Strategy<Value = Change> {
+    return prop_oneof![
+        Just(Change::Future),
+        (0..8usize).prop_map(Change::Free),
+        guard().prop_map(|(i, offset, strict, c)| Change::Restrict(i, offset, strict, c)),
+    ];
+}
+
+fn derive<T: From<i8>, D: DBM<T> + Clone>(dbm: &D, changes: &[Change]) -> D {
+    let dim = D::dimension(dbm);
+    let mut derived = dbm.clone();
+    for change in changes.iter() {
+        match *change {
+            Change::Future if !D::is_empty(&derived) => D::future(&mut derived),
+            Change::Free(k) if !D::is_empty(&derived) => D::free(&mut derived, 1 + k % (dim - 1)),
+            Change::Restrict(i, offset, strict, c) => derived = restrict_in_order(&derived, dim, &[(i, offset, strict, c)]),
+            _ => {}
+        }
+    }
+    return derived;
+}
+
+//empty zones have no canonical representation, so any two of them are equal
+fn equal<T, D: DBM<T>>(lhs: &D, rhs: &D) -> bool
+where
+    T: Ord,
+{
+    return match (D::is_empty(lhs), D::is_empty(rhs)) {
+        (true, true) => true,
+        (false, false) => D::relation(lhs, rhs) == Relation::Equal,
+        _ => false,
+    };
+}
+
+macro_rules! generate_law_tests {
+    ($($name:ident: $type:ty;)*) => {
+        $(
+            mod $name {
+                use super::*;
+
+                proptest! {
+                    #![proptest_config(config())]
+
+                    #[test]
+                    fn inclusion_is_reflexive(a in zone()) {
+                        let a: $type = build(&a);
+                        prop_assert!(DBM::is_included_in(&a, &a));
+                    }
+
+                    #[test]
+                    fn inclusion_is_antisymmetric(a in zone(), changes in prop::collection::vec(change(), 1..3)) {
+                        //b is a changed a, which is equal to a whenever the changes happen to be implied by a
+                        let a: $type = build(&a);
+                        let b = derive(&a, &changes);
+                        if DBM::is_included_in(&a, &b) && DBM::is_included_in(&b, &a) {
+                            prop_assert!(equal(&a, &b));
+                        }
+                    }
+
+                    #[test]
+                    fn inclusion_is_transitive(a in zone(), k in 1..8usize) {
+                        //a chain a <= future(a) <= free(future(a)), since both operations only add valuations
+                        let a: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&a));
+                        let mut b = a.clone();
+                        DBM::future(&mut b);
+                        let mut c = b.clone();
+                        DBM::free(&mut c, 1 + k % (DBM::dimension(&a) - 1));
+                        prop_assert!(DBM::is_included_in(&a, &b));
+                        prop_assert!(DBM::is_included_in(&b, &c));
+                        prop_assert!(DBM::is_included_in(&a, &c));
+                    }
+
+                    #[test]
+                    fn inclusion_is_transitive_for_changed_zones(a in zone(), first in change(), second in change()) {
+                        //each zone is the previous one loosened or tightened, so any two of them may be related either way
+                        let a: $type = build(&a);
+                        let b = derive(&a, &[first]);
+                        let c = derive(&b, &[second]);
+                        if DBM::is_included_in(&a, &b) && DBM::is_included_in(&b, &c) {
+                            prop_assert!(DBM::is_included_in(&a, &c));
+                        }
+                    }
+
+                    #[test]
+                    fn future_is_extensive(a in zone()) {
+                        let a: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&a));
+                        let mut future = a.clone();
+                        DBM::future(&mut future);
+                        prop_assert!(DBM::is_included_in(&a, &future));
+                    }
+
+                    #[test]
+                    fn future_is_idempotent(a in zone()) {
+                        let mut once: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&once));
+                        DBM::future(&mut once);
+                        let mut twice = once.clone();
+                        DBM::future(&mut twice);
+                        prop_assert!(equal(&once, &twice));
+                    }
+
+                    #[test]
+                    fn free_is_idempotent(a in zone(), k in 1..8usize) {
+                        let mut once: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&once));
+                        let clock = 1 + k % (a.dim - 1);
+                        DBM::free(&mut once, clock);
+                        let mut twice = once.clone();
+                        DBM::free(&mut twice, clock);
+                        prop_assert!(equal(&once, &twice));
+                    }
+
+                    #[test]
+                    fn restrict_is_commutative(a in zone(), first in guard(), second in guard()) {
+                        let dim = a.dim;
+                        let a: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&a));
+                        let left = restrict_in_order(&a, dim, &[first, second]);
+                        let right = restrict_in_order(&a, dim, &[second, first]);
+                        prop_assert!(equal(&left, &right));
+                    }
+
+                    #[test]
+                    fn close_is_idempotent(a in zone()) {
+                        let a: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&a));
+                        let mut once = a.clone();
+                        DBM::close(&mut once);
+                        let mut twice = once.clone();
+                        DBM::close(&mut twice);
+                        prop_assert!(equal(&once, &a)); //operations leave the DBM closed already
+                        prop_assert!(equal(&once, &twice));
+                    }
+
+                    #[test]
+                    fn free_after_copy_is_free(a in zone(), k in 1..8usize, l in 1..8usize) {
+                        //copying into a clock is forgotten when the clock is freed right after
+                        let a: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&a));
+                        let (to, from) = (1 + k % (DBM::dimension(&a) - 1), 1 + l % (DBM::dimension(&a) - 1));
+                        let mut copied = a.clone();
+                        DBM::copy(&mut copied, to, from);
+                        DBM::free(&mut copied, to);
+                        let mut freed = a.clone();
+                        DBM::free(&mut freed, to);
+                        prop_assert!(equal(&copied, &freed));
+                    }
+
+                    #[test]
+                    fn copy_after_free_restores_equal_clocks(a in zone(), k in 1..8usize, l in 1..8usize) {
+                        //as in test_free_and_copy: once two clocks are equal, freeing one and copying the other back
+                        //restores the zone
+                        let mut a: $type = build(&a);
+                        prop_assume!(!DBM::is_empty(&a));
+                        let (to, from) = (1 + k % (DBM::dimension(&a) - 1), 1 + l % (DBM::dimension(&a) - 1));
+                        prop_assume!(to != from);
+                        DBM::copy(&mut a, to, from);
+                        let mut restored = a.clone();
+                        DBM::free(&mut restored, to);
+                        DBM::copy(&mut restored, to, from);
+                        prop_assert!(equal(&restored, &a));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+generate_law_tests! {
+    udbm: UDBM;
+    rdbm: RDBM<i32>;
+    rdbm_v1: RDBM_V1<i32>;
+    rdbm_bitvec: RDBM_BITVEC<i32>;
+    pardibaal: PARDIBAAL;
+    reference: REFERENCE;
+}
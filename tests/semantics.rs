@@ -0,0 +1,260 @@
+//Checks the DBM operations against their mathematical definitions rather than against another backend. For a random
+//zone with at most four clocks, every valuation on a grid of integer and half-integer points is tested for membership in
+//the result of an operation, and compared with whether the definition of the operation puts it there.
+//
+//Valuations are kept in quarter units. The constants of a zone are integers and the grid points are half-integers, so
+//every interval a definition quantifies over has half-integer ends, and searching it in quarter steps finds a witness
+//whenever one exists.
+
+use dbm_baenk::{Bound, DBM, PARDIBAAL, RDBM, RDBM_BITVEC, RDBM_V1, REFERENCE, UDBM};
+use proptest::prelude::*;
+
+const QUARTER: i64 = 4;
+//the grid covers clock values from 0 to GRID in half steps
+const GRID: i64 = 4;
+const MAX_CONSTANT: i8 = 3;
+
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    Restrict(usize, usize, bool, i8),
+    Future,
+    Free(usize),
+    Assign(usize, i8),
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    return prop_oneof![
+        3 => (0..8usize, 0..8usize, any::<bool>(), -MAX_CONSTANT..=MAX_CONSTANT).prop_map(|(i, offset, strict, c)| Step::Restrict(i, offset, strict, c)),
+        1 => Just(Step::Future),
+        1 => (0..8usize).prop_map(Step::Free),
+        1 => (0..8usize, 0..=MAX_CONSTANT).prop_map(|(clock, value)| Step::Assign(clock, value)),
+    ];
+}
+
+#[derive(Clone, Debug)]
+struct Zone {
+    dim: usize,
+    steps: Vec<Step>,
+}
+
+//one to four clocks besides the reference clock
+fn zone() -> impl Strategy<Value = Zone> {
+    return (2..=5usize, prop::collection::vec(step(), 0..8)).prop_map(|(dim, steps)| Zone { dim, steps });
+}
+
+fn bound<T: From<i8>>(strict: bool, constant: i8) -> Bound<T> {
+    return match strict {
+        true => Bound::LessThan(constant.into()),
+        false => Bound::LessThanEqual(constant.into()),
+    };
+}
+
+//clock indices are drawn large and reduced modulo the dimension, j as an offset from i so that the two always differ
+fn clocks(dim: usize, i: usize, offset: usize) -> (usize, usize) {
+    return (i % dim, (i % dim + 1 + offset % (dim - 1)) % dim);
+}
+
+fn clock(dim: usize, k: usize) -> usize {
+    return 1 + k % (dim - 1);
+}
+
+fn build<T: From<i8>, D: DBM<T>>(zone: &Zone) -> D {
+    let mut dbm = D::init(zone.dim);
+    for step in zone.steps.iter() {
+        if D::is_empty(&dbm) {
+            break;
+        }
+        match *step {
+            Step::Restrict(i, offset, strict, c) => {
+                let (i, j) = clocks(zone.dim, i, offset);
+                D::restrict(&mut dbm, i, j, bound(strict, c))
+            }
+            Step::Future => D::future(&mut dbm),
+            Step::Free(k) => D::free(&mut dbm, clock(zone.dim, k)),
+            Step::Assign(k, value) => D::assign(&mut dbm, clock(zone.dim, k), value.into()),
+        }
+    }
+    return dbm;
+}
+
+fn scale<T: Into<i64>>(bound: Bound<T>) -> Bound<i64> {
+    return match bound {
+        Bound::LessThan(constant) => Bound::LessThan(constant.into() * QUARTER),
+        Bound::LessThanEqual(constant) => Bound::LessThanEqual(constant.into() * QUARTER),
+        Bound::Infinity => Bound::Infinity,
+    };
+}
+
+//the bounds of a non-empty zone in quarter units
+struct Matrix(Vec<Vec<Bound<i64>>>);
+
+impl Matrix {
+    fn of<T: Into<i64>, D: DBM<T>>(dbm: &D) -> Option<Matrix> {
+        if D::is_empty(dbm) {
+            return None;
+        }
+        return Some(Matrix(D::to_matrix(dbm).into_iter().map(|row| row.into_iter().map(scale).collect()).collect()));
+    }
+
+    fn contains(&self, point: &[i64]) -> bool {
+        return self.0.iter().enumerate().all(|(i, row)| row.iter().enumerate().all(|(j, bound)| satisfies(bound, point[i] - point[j])));
+    }
+
+    //witnesses never need to go beyond the largest constant and the grid
+    fn search_limit(&self) -> i64 {
+        let largest = self.0.iter().flatten().filter_map(|bound| bound.constant()).map(|constant| constant.abs()).max();
+        return largest.unwrap_or(0) + (GRID + 1) * QUARTER;
+    }
+
+    //whether some value of `clock` puts the point in the zone
+    fn contains_for_some(&self, point: &[i64], clock: usize) -> bool {
+        let mut point = point.to_vec();
+        return (0..=self.search_limit()).any(|value| {
+            point[clock] = value;
+            self.contains(&point)
+        });
+    }
+
+    //whether the point is in the zone after letting every clock run for `delay`
+    fn contains_delayed(&self, point: &[i64], delay: i64) -> bool {
+        let delayed: Vec<i64> = point.iter().enumerate().map(|(k, value)| if k == 0 { 0 } else { value + delay }).collect();
+        return self.contains(&delayed);
+    }
+}
+
+fn satisfies(bound: &Bound<i64>, difference: i64) -> bool {
+    return match *bound {
+        Bound::LessThan(constant) => difference < constant,
+        Bound::LessThanEqual(constant) => difference <= constant,
+        Bound::Infinity => true,
+    };
+}
+
+//every valuation with clock values in 0, 1/2, ..., GRID, with the reference clock at zero
+fn grid(dim: usize) -> Vec<Vec<i64>> {
+    let mut points = vec![vec![0]];
+    for _ in 1..dim {
+        points = points
+            .into_iter()
+            .flat_map(|point| {
+                (0..=2 * GRID).map(move |half| {
+                    let mut point = point.clone();
+                    point.push(half * QUARTER / 2);
+                    point
+                })
+            })
+            .collect();
+    }
+    return points;
+}
+
+//compares the result of an operation with its definition on every grid point
+fn check<T: Into<i64>, D: DBM<T>>(result: &D, expected: impl Fn(&[i64]) -> bool) -> Result<(), TestCaseError> {
+    let matrix = Matrix::of(result);
+    for point in grid(D::dimension(result)) {
+        let actual = matches!(&matrix, Some(matrix) if matrix.contains(&point));
+        let halves: Vec<f64> = point.iter().map(|&value| value as f64 / QUARTER as f64).collect();
+        prop_assert_eq!(actual, expected(&point), "at {:?}", halves);
+    }
+    return Ok(());
+}
+
+macro_rules! generate_semantic_tests {
+    ($($name:ident: $type:ty;)*) => {
+        $(
+            mod $name {
+                use super::*;
+
+                fn non_empty() -> impl Strategy<Value = Zone> {
+                    return zone().prop_filter("the zone is empty", |zone| !DBM::is_empty(&build::<_, $type>(zone)));
+                }
+
+                //the zone both as a DBM to operate on and as the matrix it was before the operation
+                fn original(zone: &Zone) -> ($type, Matrix) {
+                    let dbm: $type = build(zone);
+                    let matrix = Matrix::of(&dbm).expect("the zone is not empty");
+                    return (dbm, matrix);
+                }
+
+                proptest! {
+                    #![proptest_config(ProptestConfig::with_cases(16))]
+
+                    #[test]
+                    fn restrict(zone in non_empty(), i in 0..8usize, offset in 0..8usize, strict in any::<bool>(), c in -MAX_CONSTANT..=MAX_CONSTANT) {
+                        let (mut dbm, original) = original(&zone);
+                        let (i, j) = clocks(zone.dim, i, offset);
+                        let constraint = scale::<i64>(bound(strict, c));
+                        DBM::restrict(&mut dbm, i, j, bound(strict, c));
+                        check(&dbm, |point| original.contains(point) && satisfies(&constraint, point[i] - point[j]))?;
+                    }
+
+                    #[test]
+                    fn future(zone in non_empty()) {
+                        let (mut dbm, original) = original(&zone);
+                        DBM::future(&mut dbm);
+                        //the point is reached by a delay no longer than its smallest clock
+                        check(&dbm, |point| {
+                            let longest = point[1..].iter().min().cloned().unwrap_or(0);
+                            (0..=longest).any(|delay| original.contains_delayed(point, -delay))
+                        })?;
+                    }
+
+                    #[test]
+                    fn past(zone in non_empty()) {
+                        let (mut dbm, original) = original(&zone);
+                        DBM::past(&mut dbm);
+                        check(&dbm, |point| (0..=original.search_limit()).any(|delay| original.contains_delayed(point, delay)))?;
+                    }
+
+                    #[test]
+                    fn free(zone in non_empty(), k in 0..8usize) {
+                        let (mut dbm, original) = original(&zone);
+                        let k = clock(zone.dim, k);
+                        DBM::free(&mut dbm, k);
+                        check(&dbm, |point| original.contains_for_some(point, k))?;
+                    }
+
+                    #[test]
+                    fn assign(zone in non_empty(), k in 0..8usize, value in 0..=MAX_CONSTANT) {
+                        let (mut dbm, original) = original(&zone);
+                        let k = clock(zone.dim, k);
+                        DBM::assign(&mut dbm, k, value.into());
+                        check(&dbm, |point| point[k] == i64::from(value) * QUARTER && original.contains_for_some(point, k))?;
+                    }
+
+                    #[test]
+                    fn copy(zone in non_empty(), to in 0..8usize, from in 0..8usize) {
+                        let (mut dbm, original) = original(&zone);
+                        let (to, from) = (clock(zone.dim, to), clock(zone.dim, from));
+                        DBM::copy(&mut dbm, to, from);
+                        check(&dbm, |point| match to == from {
+                            true => original.contains(point),
+                            false => point[to] == point[from] && original.contains_for_some(point, to),
+                        })?;
+                    }
+
+                    #[test]
+                    fn shift(zone in non_empty(), k in 0..8usize, constant in 0..=MAX_CONSTANT) {
+                        let (mut dbm, original) = original(&zone);
+                        let k = clock(zone.dim, k);
+                        DBM::shift(&mut dbm, k, constant.into());
+                        check(&dbm, |point| {
+                            let mut before = point.to_vec();
+                            before[k] -= i64::from(constant) * QUARTER;
+                            original.contains(&before)
+                        })?;
+                    }
+                }
+            }
+        )*
+    };
+}
+
+generate_semantic_tests! {
+    udbm: UDBM;
+    rdbm: RDBM<i32>;
+    rdbm_v1: RDBM_V1<i32>;
+    rdbm_bitvec: RDBM_BITVEC<i32>;
+    pardibaal: PARDIBAAL;
+    reference: REFERENCE;
+}